@@ -0,0 +1,40 @@
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::error::ResultExt;
+use super::fetch_client_detail::ClientDetail;
+use super::scrape;
+use super::update_client::UpdateClientInput;
+use super::update_client::build_client_form;
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Add a brand-new neighbor and return their client ID.
+    ///
+    /// `client` is the full client form to submit; its `id` is ignored.
+    /// Fails with [`ServWareError::Validation`] if ServWare shows the form
+    /// again with error messages instead of saving it.
+    pub async fn create_client(&self, client: &ClientDetail) -> Result<u64> {
+        let form = build_client_form(client, &UpdateClientInput::default());
+        let url = self.new_client_url();
+
+        tracing::debug!(url, fields = form.len(), "posting new client");
+
+        let response = self
+            .send(|c| c.post(&url).form(&form))
+            .await
+            .context("create client POST failed")?;
+
+        let landed = response.url().clone();
+        scrape::check_saved(response, "create client").await?;
+
+        let id = scrape::created_id(&landed, "/app/clients/").ok_or_else(|| {
+            ServWareError::PageChanged(format!("no client ID in {landed} after saving"))
+        })?;
+        tracing::info!(client_id = id, "client created");
+        Ok(id)
+    }
+}
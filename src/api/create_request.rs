@@ -0,0 +1,43 @@
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::error::ResultExt;
+use super::fetch_request_detail::RequestDetail;
+use super::scrape;
+use super::update_request::UpdateRequestInput;
+use super::update_request::build_update_form;
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Open a new assistance request for an existing neighbor and return its
+    /// request ID.
+    ///
+    /// `request` is the full request form to submit, normally with status
+    /// `"Open"`; its `id` is ignored. Fails with
+    /// [`ServWareError::Validation`] if ServWare shows the form again with
+    /// error messages instead of saving it.
+    pub async fn create_request(&self, client_id: u64, request: &RequestDetail) -> Result<u64> {
+        let mut form = vec![("clientId".to_string(), client_id.to_string())];
+        form.extend(build_update_form(request, &UpdateRequestInput::default()));
+        let url = self.new_request_url();
+
+        tracing::debug!(url, client_id, fields = form.len(), "posting new request");
+
+        let response = self
+            .send(|client| client.post(&url).form(&form))
+            .await
+            .context("create request POST failed")?;
+
+        let landed = response.url().clone();
+        scrape::check_saved(response, "create request").await?;
+
+        let id = scrape::created_id(&landed, "/app/assistancerequests/").ok_or_else(|| {
+            ServWareError::PageChanged(format!("no request ID in {landed} after saving"))
+        })?;
+        tracing::info!(client_id, request_id = id, "request created");
+        Ok(id)
+    }
+}
@@ -0,0 +1,164 @@
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use futures_util::Stream;
+use futures_util::TryStreamExt;
+use futures_util::stream;
+use reqwest::Url;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::error::ResultExt;
+use super::fetch_requests::strip_json_nulls;
+
+// ---------------------------------------------------------------------------
+// Response type
+// ---------------------------------------------------------------------------
+
+/// DataTables server-side processing response envelope, shared by every
+/// list ServWare pages this way (requests, neighbors, follow-ups, mileage
+/// and hours).
+#[derive(Debug, Clone, Deserialize)]
+pub struct DataTablesResponse<T> {
+    #[serde(rename = "sEcho")]
+    pub s_echo: u32,
+    #[serde(rename = "iTotalRecords")]
+    pub i_total_records: u32,
+    #[serde(rename = "iTotalDisplayRecords")]
+    pub i_total_display_records: u32,
+    #[serde(rename = "aaData")]
+    pub aa_data: Vec<T>,
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// GET one page of a DataTables list. `what` names the list in errors
+    /// and logs, e.g. `"clients"`.
+    pub(crate) async fn fetch_data_table<T: DeserializeOwned>(
+        &self,
+        url: Url,
+        what: &str,
+    ) -> Result<DataTablesResponse<T>> {
+        tracing::debug!(%url, "fetching {what}");
+
+        let response = self
+            .send(|client| {
+                client
+                    .get(url.clone())
+                    .header("X-Requested-With", "XMLHttpRequest")
+                    .header("Accept", "application/json, text/javascript, */*; q=0.01")
+            })
+            .await
+            .context(format!("fetch {what} HTTP request failed"))?;
+
+        let status = response.status();
+        tracing::debug!(%status, "fetch {what} response");
+
+        if !status.is_success() {
+            return Err(ServWareError::status(&format!("fetch {what}"), status));
+        }
+
+        let mut raw: serde_json::Value = response
+            .json()
+            .await
+            .context(format!("failed to parse fetch {what} response JSON"))?;
+
+        strip_json_nulls(&mut raw);
+
+        let body: DataTablesResponse<T> = serde_json::from_value(raw)
+            .context(format!("failed to deserialize fetch {what} response"))?;
+
+        tracing::debug!(
+            total = body.i_total_display_records,
+            returned = body.aa_data.len(),
+            "fetched {what}"
+        );
+
+        Ok(body)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// `url` with the query DataTables sends for one page: the columns, the
+/// page window, then `extra` (sorting, search, filters) and a cache buster.
+pub(crate) fn page_url(
+    url: &str,
+    columns: &[&str],
+    display_start: u32,
+    display_length: u32,
+    extra: &[(&str, &str)],
+) -> Result<Url> {
+    let cache_buster = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+        .to_string();
+
+    let mut full_url = Url::parse(url).context(format!("failed to parse list URL {url}"))?;
+    {
+        let mut qs = full_url.query_pairs_mut();
+        qs.append_pair("sEcho", "1");
+        qs.append_pair("iColumns", &columns.len().to_string());
+        qs.append_pair("sColumns", &columns.join(","));
+        qs.append_pair("iDisplayStart", &display_start.to_string());
+        qs.append_pair("iDisplayLength", &display_length.to_string());
+        for (i, column) in columns.iter().enumerate() {
+            qs.append_pair(&format!("mDataProp_{i}"), column);
+        }
+        for &(k, v) in extra {
+            qs.append_pair(k, v);
+        }
+        qs.append_pair("_", &cache_buster);
+    }
+    Ok(full_url)
+}
+
+/// Yield every row of a DataTables list, one page at a time.
+///
+/// `fetch_page` gets the page starting at a given `iDisplayStart`. Pages
+/// of `display_length` rows are requested from `display_start` on until
+/// the server's `iTotalDisplayRecords` is reached (or it returns an empty
+/// page).
+pub(crate) fn paginate<'a, T, F, Fut>(
+    display_start: u32,
+    display_length: u32,
+    fetch_page: F,
+) -> impl Stream<Item = Result<T>> + Send + 'a
+where
+    T: Send + 'a,
+    F: Fn(u32) -> Fut + Send + 'a,
+    Fut: Future<Output = Result<DataTablesResponse<T>>> + Send + 'a,
+{
+    let pages = stream::try_unfold(Some((display_start, fetch_page)), move |next| async move {
+        let Some((start, fetch_page)) = next else {
+            return Ok(None);
+        };
+        if display_length == 0 {
+            return Err(ServWareError::InvalidInput(
+                "page size must be at least 1".into(),
+            ));
+        }
+
+        let page = fetch_page(start).await?;
+        let returned = page.aa_data.len() as u32;
+        let start = start + returned;
+
+        tracing::debug!(start, total = page.i_total_display_records, "fetched page");
+
+        let more = returned > 0 && start < page.i_total_display_records;
+        Ok(Some((page.aa_data, more.then_some((start, fetch_page)))))
+    });
+
+    pages
+        .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+        .try_flatten()
+}
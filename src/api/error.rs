@@ -0,0 +1,205 @@
+use std::fmt;
+
+use reqwest::StatusCode;
+
+/// Result of every [`ServWare`](super::ServWare) call.
+pub type Result<T, E = ServWareError> = std::result::Result<T, E>;
+
+/// Why a ServWare call failed, so callers can retry, log in again or skip
+/// a row depending on the kind of failure.
+///
+/// Errors convert into `anyhow::Error` with their causes intact, for
+/// callers that only report them.
+#[derive(Debug, thiserror::Error)]
+pub enum ServWareError {
+    /// ServWare turned the login away: wrong username or password.
+    #[error("login failed: redirected back to login page (bad credentials?)")]
+    BadCredentials,
+
+    /// The session expired and logging in again failed.
+    #[error("session expired and logging in again failed: {0}")]
+    ReloginFailed(#[source] Box<ServWareError>),
+
+    /// The session expired and there is no password to log in again with,
+    /// because it was resumed from a session file. Log in afresh (or delete
+    /// the session file) and run the command again.
+    #[error("session expired and there is no password to log in again with")]
+    NoCredentials,
+
+    /// ServWare sent the call to its login page and the session could not
+    /// be renewed, e.g. it was still rejected right after a fresh login.
+    #[error("session expired")]
+    SessionExpired,
+
+    /// The record asked for doesn't exist, e.g. `"request 101"`.
+    #[error("{0} not found")]
+    NotFound(String),
+
+    /// A page didn't have the form, table or dropdown we scrape. Usually
+    /// means ServWare changed its HTML.
+    #[error("{0} — page structure may have changed")]
+    PageChanged(String),
+
+    /// A JSON response didn't have the expected shape.
+    #[error("{context}")]
+    Json {
+        context: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// ServWare didn't save a form: it showed the form again with error
+    /// messages next to the fields instead of redirecting.
+    #[error("{context} was rejected: {}", self::describe(.errors))]
+    Validation {
+        context: String,
+        errors: Vec<FieldError>,
+    },
+
+    /// ServWare answered with an unexpected HTTP status.
+    #[error("{context} failed with status {status}")]
+    Status { context: String, status: StatusCode },
+
+    /// The request couldn't be sent or its response couldn't be read, e.g.
+    /// a dropped connection or a timeout.
+    #[error("{context}")]
+    Network {
+        context: String,
+        #[source]
+        source: reqwest::Error,
+    },
+
+    /// A bad argument, such as a zero page size or an invalid base URL.
+    #[error("{0}")]
+    InvalidInput(String),
+
+    /// Anything else, e.g. a session file that couldn't be written.
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+/// One error message from a form ServWare refused to save.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// The form field the message is about, e.g. `"monetaryValue"`, or
+    /// `None` for a message about the whole form.
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "{field}: {}", self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+/// `"dateProvided: is required; monetaryValue: must be a number"`.
+fn describe(errors: &[FieldError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl ServWareError {
+    /// An unexpected HTTP status from `what`, e.g. `"fetch clients"`.
+    pub(crate) fn status(what: &str, status: StatusCode) -> Self {
+        Self::Status {
+            context: what.to_string(),
+            status,
+        }
+    }
+
+    /// Worth retrying as is: a network failure or a server-side hiccup.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Network { source, .. } => !source.is_decode() && !source.is_builder(),
+            Self::Status { status, .. } => {
+                status.is_server_error()
+                    || *status == StatusCode::REQUEST_TIMEOUT
+                    || *status == StatusCode::TOO_MANY_REQUESTS
+            }
+            _ => false,
+        }
+    }
+
+    /// The login or session is the problem; logging in again (with the
+    /// right password) may fix it.
+    pub fn is_session(&self) -> bool {
+        matches!(
+            self,
+            Self::BadCredentials
+                | Self::ReloginFailed(_)
+                | Self::NoCredentials
+                | Self::SessionExpired
+        )
+    }
+
+    /// The record doesn't exist, whether we noticed or ServWare said 404.
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Self::NotFound(_) => true,
+            Self::Status { status, .. } => *status == StatusCode::NOT_FOUND,
+            _ => false,
+        }
+    }
+
+    /// The field messages of a [`Validation`](Self::Validation) error;
+    /// empty for any other kind.
+    pub fn field_errors(&self) -> &[FieldError] {
+        match self {
+            Self::Validation { errors, .. } => errors,
+            _ => &[],
+        }
+    }
+
+    /// Replace the description of what failed, keeping the cause.
+    fn with_context(self, context: String) -> Self {
+        match self {
+            Self::Json { source, .. } => Self::Json { context, source },
+            Self::Network { source, .. } => Self::Network { context, source },
+            Self::Other(e) => Self::Other(e.context(context)),
+            other => other,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ServWareError {
+    fn from(source: reqwest::Error) -> Self {
+        Self::Network {
+            context: "HTTP request failed".into(),
+            source,
+        }
+    }
+}
+
+impl From<serde_json::Error> for ServWareError {
+    fn from(source: serde_json::Error) -> Self {
+        Self::Json {
+            context: "unexpected JSON response".into(),
+            source,
+        }
+    }
+}
+
+impl From<url::ParseError> for ServWareError {
+    fn from(e: url::ParseError) -> Self {
+        Self::InvalidInput(format!("invalid URL: {e}"))
+    }
+}
+
+/// `anyhow::Context` for [`ServWareError`]: says what was being done
+/// without losing the kind of failure.
+pub(crate) trait ResultExt<T> {
+    fn context(self, context: impl Into<String>) -> Result<T>;
+}
+
+impl<T, E: Into<ServWareError>> ResultExt<T> for Result<T, E> {
+    fn context(self, context: impl Into<String>) -> Result<T> {
+        self.map_err(|e| e.into().with_context(context.into()))
+    }
+}
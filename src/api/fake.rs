@@ -0,0 +1,615 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::Utc;
+
+use super::Result;
+use super::ServWareApi;
+use super::ServWareError;
+use super::fetch_activity_report::ReportRow;
+use super::fetch_approvals::Approval;
+use super::fetch_calendar::CalendarEvent;
+use super::fetch_calendar::CalendarFeed;
+use super::fetch_client_detail::ClientDetail;
+use super::fetch_item_value::ItemValue;
+use super::fetch_lookups::Lookups;
+use super::fetch_members::Member;
+use super::fetch_request_detail::RequestDetail;
+use super::fetch_requests::AssistanceItem;
+use super::fetch_requests::AssistanceRequest;
+use super::fetch_requests::AssistanceType;
+use super::fetch_requests::Client;
+use super::fetch_requests::FetchRequestsParams;
+use super::fetch_requests::FetchRequestsResponse;
+use super::fetch_statistics::Statistic;
+use super::follow_ups::FollowUp;
+use super::follow_ups::NewFollowUp;
+use super::pending_assistance::PendingAssistance;
+use super::service_hours::NewServiceHours;
+use super::update_assistance::UpdateAssistanceInput;
+use super::update_client::UpdateClientInput;
+use super::update_request::UpdateRequestInput;
+
+/// Origin reported by [`FakeServWare`]; links built from it go nowhere.
+const FAKE_BASE_URL: &str = "https://servware.invalid";
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// In-memory [`ServWareApi`] backend for exercising workflow logic without
+/// a server.
+///
+/// Updates are applied to the stored requests (status, visit fields, new
+/// assistance items, the neighbor's contact details) so a caller can fetch
+/// again and see the result. New requests are added to them; new neighbors
+/// are kept alongside until they have one.
+/// Pending assistance is every stored item still marked pending.
+/// Statistics are counted from the stored requests, ignoring fiscal years.
+/// Item values come from [`FakeServWare::with_item_value`] and are the same
+/// for every client. The activity report is whatever
+/// [`FakeServWare::with_activity_report`] set, for any dates. Lookups and
+/// calendar events are configured the same way; new follow-ups are added to
+/// the ones from [`FakeServWare::with_follow_ups`].
+/// Neighbor search matches names, phone numbers and street addresses.
+#[derive(Debug, Default)]
+pub struct FakeServWare {
+    members: Vec<Member>,
+    requests: Mutex<Vec<AssistanceRequest>>,
+    clients: Mutex<Vec<Client>>,
+    service_hours: Mutex<Vec<NewServiceHours>>,
+    item_values: HashMap<String, f64>,
+    approvals: HashMap<u64, Vec<Approval>>,
+    activity_report: Vec<ReportRow>,
+    lookups: Lookups,
+    follow_ups: Mutex<Vec<FollowUp>>,
+    calendar: Vec<(CalendarFeed, CalendarEvent)>,
+}
+
+impl FakeServWare {
+    pub fn new(members: Vec<Member>, requests: Vec<AssistanceRequest>) -> Self {
+        Self {
+            members,
+            requests: Mutex::new(requests),
+            ..Default::default()
+        }
+    }
+
+    /// Configure the rows returned for the activity report.
+    pub fn with_activity_report(mut self, rows: Vec<ReportRow>) -> Self {
+        self.activity_report = rows;
+        self
+    }
+
+    /// Configure the events on one of the calendars.
+    pub fn with_calendar_events(mut self, feed: CalendarFeed, events: Vec<CalendarEvent>) -> Self {
+        self.calendar
+            .extend(events.into_iter().map(|event| (feed, event)));
+        self
+    }
+
+    /// Configure the follow-ups that already exist.
+    pub fn with_follow_ups(mut self, follow_ups: Vec<FollowUp>) -> Self {
+        self.follow_ups = Mutex::new(follow_ups);
+        self
+    }
+
+    /// Configure the lookup tables, the same for every request.
+    pub fn with_lookups(mut self, lookups: Lookups) -> Self {
+        self.lookups = lookups;
+        self
+    }
+
+    /// Configure the approval chain returned for a request.
+    pub fn with_approvals(mut self, request_id: u64, approvals: Vec<Approval>) -> Self {
+        self.approvals.insert(request_id, approvals);
+        self
+    }
+
+    /// Configure the default value returned for an assistance type.
+    pub fn with_item_value(mut self, assistance_type_id: &str, value: f64) -> Self {
+        self.item_values
+            .insert(assistance_type_id.to_string(), value);
+        self
+    }
+
+    /// Snapshot of every stored request.
+    pub fn requests(&self) -> Vec<AssistanceRequest> {
+        self.lock().clone()
+    }
+
+    /// Snapshot of one stored request.
+    pub fn request(&self, id: u64) -> Option<AssistanceRequest> {
+        self.lock().iter().find(|r| r.id == id).cloned()
+    }
+
+    /// Every mileage and hours entry logged so far.
+    pub fn service_hours(&self) -> Vec<NewServiceHours> {
+        self.service_hours
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Every follow-up, completed or not.
+    pub fn follow_ups(&self) -> Vec<FollowUp> {
+        self.lock_follow_ups().clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<AssistanceRequest>> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Neighbors added through [`ServWareApi::create_client`]. Always taken
+    /// after [`FakeServWare::lock`] when both are held.
+    fn lock_clients(&self) -> MutexGuard<'_, Vec<Client>> {
+        self.clients
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn lock_follow_ups(&self) -> MutexGuard<'_, Vec<FollowUp>> {
+        self.follow_ups
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// A neighbor from the stored requests or the ones added since.
+    fn client(&self, id: u64) -> Option<Client> {
+        let from_requests = self
+            .lock()
+            .iter()
+            .map(|r| &r.client)
+            .find(|c| c.id == id)
+            .cloned();
+        from_requests.or_else(|| self.lock_clients().iter().find(|c| c.id == id).cloned())
+    }
+
+    fn with_request<T>(&self, id: u64, f: impl FnOnce(&mut AssistanceRequest) -> T) -> Result<T> {
+        let mut requests = self.lock();
+        let request = requests
+            .iter_mut()
+            .find(|r| r.id == id)
+            .ok_or_else(|| ServWareError::NotFound(format!("request {id}")))?;
+        Ok(f(request))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWareApi for FakeServWare {
+    async fn fetch_requests(&self, params: &FetchRequestsParams) -> Result<FetchRequestsResponse> {
+        let requests = self.lock();
+        let search = params.search.to_lowercase();
+
+        let matching: Vec<&AssistanceRequest> = requests
+            .iter()
+            .filter(|r| params.filter_by_status.is_empty() || r.status == params.filter_by_status)
+            .filter(|r| {
+                search.is_empty()
+                    || r.id.to_string().contains(&search)
+                    || r.client.first_name.to_lowercase().contains(&search)
+                    || r.client.last_name.to_lowercase().contains(&search)
+            })
+            .collect();
+
+        Ok(FetchRequestsResponse {
+            s_echo: 1,
+            i_total_records: requests.len() as u32,
+            i_total_display_records: matching.len() as u32,
+            aa_data: matching
+                .into_iter()
+                .skip(params.display_start as usize)
+                .take(params.display_length as usize)
+                .cloned()
+                .collect(),
+        })
+    }
+
+    async fn fetch_members(&self, request_id: u64) -> Result<Vec<Member>> {
+        self.with_request(request_id, |_| ())?;
+        if self.members.is_empty() {
+            return Err(ServWareError::PageChanged(format!(
+                "no members found in request {request_id}"
+            )));
+        }
+        Ok(self.members.clone())
+    }
+
+    async fn update_request(&self, request_id: u64, input: &UpdateRequestInput) -> Result<()> {
+        self.with_request(request_id, |r| {
+            if let Some(status) = &input.status {
+                r.status = status.clone();
+            }
+            if let Some(member_id) = &input.request_assigned_to_member_id {
+                r.request_assigned_to_member = Some(member_id.clone());
+            }
+            if let Some(member_id) = &input.visit_assigned_to_member_id {
+                r.visit_assigned_to_member = Some(member_id.clone());
+            }
+            if let Some(required) = input.home_visit_required {
+                r.home_visit_required = required;
+            }
+            if let Some(completed) = input.visit_completed {
+                r.visit_completed = completed;
+            }
+            if let Some(date) = &input.visit_scheduled_date {
+                r.visit_scheduled_date = Some(date.clone());
+            }
+            if let Some(notes) = &input.visit_notes {
+                r.visit_notes = notes.clone();
+            }
+        })
+    }
+
+    async fn update_assistance(
+        &self,
+        request_id: u64,
+        input: &UpdateAssistanceInput,
+    ) -> Result<()> {
+        let invalid = |what: &str| ServWareError::InvalidInput(format!("{what} is not a number"));
+        let monetary_value: f64 = input
+            .monetary_value
+            .parse()
+            .map_err(|_| invalid("monetary value"))?;
+        let quantity: u32 = input.quantity.parse().map_err(|_| invalid("quantity"))?;
+        let type_id: u64 = input
+            .assistance_type_id
+            .parse()
+            .map_err(|_| invalid("assistance type ID"))?;
+
+        self.with_request(request_id, |r| {
+            let id = r.assistance_items.len() as u64 + 1;
+            r.assistance_items.push(AssistanceItem {
+                id,
+                monetary_value,
+                total_assistance_item_value: monetary_value * f64::from(quantity),
+                quantity,
+                date_provided: input.date_provided.clone(),
+                notes: input.notes.clone(),
+                check_requested: input.check_requested,
+                payee_name: input.payee_name.clone(),
+                pending: input.pending,
+                assistance_type: AssistanceType {
+                    id: type_id,
+                    ..Default::default()
+                },
+                ..Default::default()
+            });
+        })
+    }
+
+    async fn update_client(&self, client_id: u64, input: &UpdateClientInput) -> Result<()> {
+        let mut requests = self.lock();
+        let mut created = self.lock_clients();
+        let mut clients = requests
+            .iter_mut()
+            .map(|r| &mut r.client)
+            .chain(created.iter_mut())
+            .filter(|c| c.id == client_id)
+            .peekable();
+        if clients.peek().is_none() {
+            return Err(ServWareError::NotFound(format!("client {client_id}")));
+        }
+
+        for c in clients {
+            let fields = [
+                (&input.first_name, &mut c.first_name),
+                (&input.last_name, &mut c.last_name),
+                (&input.home_phone, &mut c.home_phone),
+                (&input.work_phone, &mut c.work_phone),
+                (&input.mobile_phone, &mut c.mobile_phone),
+                (&input.email_address, &mut c.email_address),
+                (&input.street_address_line1, &mut c.street_address_line1),
+                (&input.street_address_line2, &mut c.street_address_line2),
+                (&input.city, &mut c.city),
+                (&input.state_code, &mut c.state_code),
+                (&input.postal_code, &mut c.postal_code),
+                (&input.notes, &mut c.notes),
+            ];
+            for (value, field) in fields {
+                if let Some(value) = value {
+                    field.clone_from(value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn fetch_client_detail(&self, client_id: u64) -> Result<ClientDetail> {
+        let c = self
+            .client(client_id)
+            .ok_or_else(|| ServWareError::NotFound(format!("client {client_id}")))?;
+        Ok(ClientDetail {
+            id: c.id,
+            first_name: c.first_name,
+            last_name: c.last_name,
+            middle_initial: c.middle_initial,
+            birth_date: c.birth_date,
+            home_phone: c.home_phone,
+            work_phone: c.work_phone,
+            mobile_phone: c.mobile_phone,
+            email_address: c.email_address,
+            text_communication_preferred: c.text_communication_preferred,
+            street_address_line1: c.street_address_line1,
+            street_address_line2: c.street_address_line2,
+            city: c.city,
+            state_code: c.state_code,
+            postal_code: c.postal_code,
+            parishioner: c.parishioner,
+            homeless: c.homeless,
+            disabled_client: c.disabled_client,
+            veteran: c.veteran,
+            private_client: c.private_client,
+            notes: c.notes,
+            alert_note: c.alert_note,
+        })
+    }
+
+    async fn create_client(&self, client: &ClientDetail) -> Result<u64> {
+        if client.last_name.trim().is_empty() {
+            return Err(ServWareError::InvalidInput("last name is required".into()));
+        }
+
+        let requests = self.lock();
+        let mut created = self.lock_clients();
+        let id = requests
+            .iter()
+            .map(|r| r.client.id)
+            .chain(created.iter().map(|c| c.id))
+            .max()
+            .unwrap_or_default()
+            + 1;
+        created.push(Client {
+            id,
+            first_name: client.first_name.clone(),
+            last_name: client.last_name.clone(),
+            middle_initial: client.middle_initial.clone(),
+            birth_date: client.birth_date.clone(),
+            home_phone: client.home_phone.clone(),
+            work_phone: client.work_phone.clone(),
+            mobile_phone: client.mobile_phone.clone(),
+            email_address: client.email_address.clone(),
+            text_communication_preferred: client.text_communication_preferred,
+            street_address_line1: client.street_address_line1.clone(),
+            street_address_line2: client.street_address_line2.clone(),
+            city: client.city.clone(),
+            state_code: client.state_code.clone(),
+            postal_code: client.postal_code.clone(),
+            parishioner: client.parishioner,
+            homeless: client.homeless,
+            disabled_client: client.disabled_client,
+            veteran: client.veteran,
+            private_client: client.private_client,
+            notes: client.notes.clone(),
+            alert_note: client.alert_note.clone(),
+            ..Default::default()
+        });
+        Ok(id)
+    }
+
+    async fn create_request(&self, client_id: u64, request: &RequestDetail) -> Result<u64> {
+        if request.date_requested.trim().is_empty() {
+            return Err(ServWareError::InvalidInput(
+                "date requested is required".into(),
+            ));
+        }
+        let client = self
+            .client(client_id)
+            .ok_or_else(|| ServWareError::NotFound(format!("client {client_id}")))?;
+
+        let mut requests = self.lock();
+        let id = requests.iter().map(|r| r.id).max().unwrap_or_default() + 1;
+        requests.push(AssistanceRequest {
+            id,
+            status: request.status.clone(),
+            date_requested: request.date_requested.clone(),
+            request_note: request.request_note.clone(),
+            client,
+            ..Default::default()
+        });
+        Ok(id)
+    }
+
+    async fn create_service_hours(&self, entry: &NewServiceHours) -> Result<()> {
+        for (what, value) in [
+            ("hours", &entry.hours_in_service),
+            ("mileage", &entry.mileage_in_service),
+        ] {
+            if value.parse::<f64>().is_err() {
+                return Err(ServWareError::InvalidInput(format!(
+                    "{what} is not a number"
+                )));
+            }
+        }
+        self.service_hours
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(entry.clone());
+        Ok(())
+    }
+
+    async fn fetch_pending_assistance(&self) -> Result<Vec<PendingAssistance>> {
+        let requests = self.lock();
+        let pending = requests.iter().flat_map(|r| {
+            r.assistance_items
+                .iter()
+                .filter(|i| i.pending)
+                .map(|i| PendingAssistance {
+                    request_id: r.id,
+                    item_id: i.id,
+                    neighbor: format!("{}, {}", r.client.last_name, r.client.first_name),
+                    assistance_type: i.assistance_type.name.clone(),
+                    amount: Some(i.monetary_value),
+                    payee_name: i.payee_name.clone(),
+                    promised_date: i.promised_date.clone().unwrap_or_default(),
+                    date_provided: i.date_provided.clone(),
+                })
+        });
+        Ok(pending.collect())
+    }
+
+    async fn mark_assistance_paid(
+        &self,
+        request_id: u64,
+        item_id: u64,
+        date_paid: &str,
+        check_number: &str,
+    ) -> Result<()> {
+        self.with_request(request_id, |r| {
+            let item = r
+                .assistance_items
+                .iter_mut()
+                .find(|i| i.id == item_id && i.pending)
+                .ok_or_else(|| {
+                    ServWareError::NotFound(format!(
+                        "pending item {item_id} of request {request_id}"
+                    ))
+                })?;
+            item.pending = false;
+            item.date_paid = Some(date_paid.to_string());
+            item.check_number = check_number.to_string();
+            Ok(())
+        })?
+    }
+
+    async fn fetch_activity_report(
+        &self,
+        _from: NaiveDate,
+        _to: NaiveDate,
+    ) -> Result<Vec<ReportRow>> {
+        Ok(self.activity_report.clone())
+    }
+
+    async fn fetch_statistics(&self) -> Result<Vec<Statistic>> {
+        let requests = self.lock();
+        let with_status =
+            |status: &str| requests.iter().filter(|r| r.status == status).count() as u64;
+        let neighbors: HashSet<u64> = requests.iter().map(|r| r.client.id).collect();
+
+        Ok(vec![
+            Statistic {
+                count: with_status("Completed"),
+                count_description: "Completed Requests (FY)".into(),
+            },
+            Statistic {
+                count: with_status("Open"),
+                count_description: "Open Requests (FY)".into(),
+            },
+            Statistic {
+                count: neighbors.len() as u64,
+                count_description: "Total Neighbors".into(),
+            },
+        ])
+    }
+
+    async fn fetch_item_value(
+        &self,
+        assistance_type_id: &str,
+        _client_id: u64,
+    ) -> Result<ItemValue> {
+        let value = self.item_values.get(assistance_type_id).copied();
+        Ok(ItemValue {
+            monetary_value: value,
+            raw: value.map_or(serde_json::Value::Null, serde_json::Value::from),
+        })
+    }
+
+    async fn fetch_approvals(&self, request_id: u64) -> Result<Vec<Approval>> {
+        self.with_request(request_id, |_| ())?;
+        Ok(self.approvals.get(&request_id).cloned().unwrap_or_default())
+    }
+
+    fn base_url(&self) -> &str {
+        FAKE_BASE_URL
+    }
+
+    async fn fetch_lookups(&self, request_id: u64) -> Result<Lookups> {
+        self.with_request(request_id, |_| ())?;
+        Ok(self.lookups.clone())
+    }
+
+    async fn search_clients(&self, query: &str) -> Result<Vec<Client>> {
+        let query = query.trim().to_lowercase();
+        let requests = self.lock();
+        let created = self.lock_clients();
+
+        let mut seen = HashSet::new();
+        let matching = requests
+            .iter()
+            .map(|r| &r.client)
+            .chain(created.iter())
+            .filter(|c| {
+                [
+                    &c.first_name,
+                    &c.last_name,
+                    &c.home_phone,
+                    &c.work_phone,
+                    &c.mobile_phone,
+                    &c.street_address_line1,
+                ]
+                .iter()
+                .any(|field| field.to_lowercase().contains(&query))
+            })
+            .filter(|c| seen.insert(c.id))
+            .cloned();
+        Ok(matching.collect())
+    }
+
+    async fn open_follow_ups(&self) -> Result<Vec<FollowUp>> {
+        let mut open: Vec<FollowUp> = self
+            .lock_follow_ups()
+            .iter()
+            .filter(|f| !f.completed)
+            .cloned()
+            .collect();
+        open.sort_by_key(FollowUp::due_date);
+        Ok(open)
+    }
+
+    async fn create_follow_up(&self, client_id: u64, follow_up: &NewFollowUp) -> Result<()> {
+        if follow_up.follow_up_date.trim().is_empty() {
+            return Err(ServWareError::InvalidInput(
+                "follow-up date is required".into(),
+            ));
+        }
+        let client = self
+            .client(client_id)
+            .ok_or_else(|| ServWareError::NotFound(format!("client {client_id}")))?;
+
+        let mut follow_ups = self.lock_follow_ups();
+        let id = follow_ups.iter().map(|f| f.id).max().unwrap_or_default() + 1;
+        follow_ups.push(FollowUp {
+            id,
+            follow_up_date: follow_up.follow_up_date.clone(),
+            notes: follow_up.notes.clone(),
+            assigned_to_member: Some(follow_up.assigned_to_member_id.clone())
+                .filter(|member| !member.is_empty()),
+            client: Some(client),
+            ..Default::default()
+        });
+        Ok(())
+    }
+
+    async fn fetch_calendar(
+        &self,
+        feed: CalendarFeed,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<CalendarEvent>> {
+        let events = self
+            .calendar
+            .iter()
+            .filter(|(f, event)| *f == feed && event.start >= start && event.start < end)
+            .map(|(_, event)| event.clone());
+        Ok(events.collect())
+    }
+}
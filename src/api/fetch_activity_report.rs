@@ -0,0 +1,262 @@
+use chrono::NaiveDate;
+use scraper::Html;
+use scraper::Selector;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::error::ResultExt;
+use super::scrape;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// One line of the conference activity report, e.g. `"Assistance"` /
+/// `"Food"` / `12` / `$840.00`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReportRow {
+    /// Heading the line falls under; empty before the first heading.
+    pub section: String,
+    pub description: String,
+    pub count: Option<u64>,
+    /// Dollar value, for lines that have one.
+    pub amount: Option<f64>,
+}
+
+/// Which [`ReportRow`] field a column of the report table holds.
+#[derive(Clone, Copy, PartialEq)]
+enum Column {
+    Description,
+    Count,
+    Amount,
+    Other,
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Run the conference activity report for `from` through `to`,
+    /// inclusive: the figures that go into the diocesan filings.
+    ///
+    /// Experimental: the POST parameters are unverified against ServWare.
+    /// Anything but a report table with recognizable columns and at least
+    /// one line fails with [`ServWareError::PageChanged`] rather than
+    /// returning figures that might be wrong.
+    pub async fn fetch_activity_report(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<ReportRow>> {
+        let start_date = from.format("%m/%d/%Y").to_string();
+        let end_date = to.format("%m/%d/%Y").to_string();
+        // Unverified: api.md only lists the `/app/reports` route. The field
+        // names and the `reportName` / `format` values are guesses from the
+        // report form and haven't been checked against a live capture.
+        let form = [
+            ("reportName", "conferenceActivity"),
+            ("startDate", start_date.as_str()),
+            ("endDate", end_date.as_str()),
+            ("format", "html"),
+        ];
+        let url = self.reports_url();
+
+        tracing::debug!(url, start_date, end_date, "requesting activity report");
+
+        let response = self
+            .send(|client| client.post(&url).form(&form))
+            .await
+            .context("activity report POST failed")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ServWareError::status("activity report", status));
+        }
+
+        let html = response
+            .text()
+            .await
+            .context("failed to read activity report body")?;
+
+        let rows = self::parse_activity_report(&html)?;
+        if rows.is_empty() {
+            return Err(ServWareError::PageChanged(
+                "no rows found in the activity report".into(),
+            ));
+        }
+        tracing::debug!(count = rows.len(), "parsed activity report");
+        Ok(rows)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Read the report table. A row of several `th` cells names the columns;
+/// a row with a single cell is a section heading for the lines below it.
+///
+/// Fails with [`ServWareError::PageChanged`] if there's no header row, or
+/// it lacks a description column or both count and amount, since the
+/// figures can't be trusted then.
+fn parse_activity_report(html: &str) -> Result<Vec<ReportRow>> {
+    let document = Html::parse_document(html);
+    let rows = Selector::parse("tr").expect("valid CSS selector");
+    let cells = Selector::parse("th, td").expect("valid CSS selector");
+
+    let mut columns: Option<Vec<Column>> = None;
+    let mut section = String::new();
+    let mut report = Vec::new();
+    for row in document.select(&rows) {
+        let row_cells: Vec<_> = row.select(&cells).collect();
+        let values: Vec<String> = row_cells.iter().copied().map(scrape::cell_text).collect();
+        match values.as_slice() {
+            [] => continue,
+            [heading] => {
+                section = heading.clone();
+                continue;
+            }
+            _ if row_cells.iter().all(|c| c.value().name() == "th") => {
+                let header: Vec<Column> = values.iter().map(|h| self::column(h)).collect();
+                if !header.contains(&Column::Description) {
+                    return Err(ServWareError::PageChanged(format!(
+                        "no description column in activity report header {values:?}"
+                    )));
+                }
+                if !header.contains(&Column::Count) && !header.contains(&Column::Amount) {
+                    return Err(ServWareError::PageChanged(format!(
+                        "no count or amount column in activity report header {values:?}"
+                    )));
+                }
+                columns = Some(header);
+                continue;
+            }
+            _ => {}
+        }
+        let Some(columns) = &columns else {
+            continue;
+        };
+
+        let mut line = ReportRow {
+            section: section.clone(),
+            ..Default::default()
+        };
+        for (column, value) in columns.iter().zip(values) {
+            match column {
+                Column::Description => line.description = value,
+                Column::Count => line.count = value.replace(',', "").trim().parse().ok(),
+                Column::Amount => line.amount = scrape::parse_amount(&value),
+                Column::Other => {}
+            }
+        }
+        if !line.description.is_empty() {
+            report.push(line);
+        }
+    }
+
+    if columns.is_none() {
+        return Err(ServWareError::PageChanged(
+            "no activity report table found".into(),
+        ));
+    }
+    Ok(report)
+}
+
+fn column(header: &str) -> Column {
+    let header = header.to_lowercase();
+    if ["amount", "value", "$"]
+        .iter()
+        .any(|word| header.contains(word))
+    {
+        Column::Amount
+    } else if ["count", "number", "#", "total"]
+        .iter()
+        .any(|word| header.contains(word))
+    {
+        Column::Count
+    } else if ["description", "activity", "item", "category"]
+        .iter()
+        .any(|word| header.contains(word))
+    {
+        Column::Description
+    } else {
+        Column::Other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReportRow;
+    use super::ServWareError;
+    use super::parse_activity_report;
+
+    fn line(
+        section: &str,
+        description: &str,
+        count: Option<u64>,
+        amount: Option<f64>,
+    ) -> ReportRow {
+        ReportRow {
+            section: section.into(),
+            description: description.into(),
+            count,
+            amount,
+        }
+    }
+
+    #[test]
+    fn reads_sections_and_header_columns() {
+        let html = r#"<table>
+            <tr><th>Amount</th><th>Description</th><th>Count</th></tr>
+            <tr><th colspan="3">Assistance</th></tr>
+            <tr><td>$1,840.00</td><td>Food</td><td>1,204</td></tr>
+            <tr><td></td><td>Home visits</td><td>12</td></tr>
+        </table>"#;
+
+        assert_eq!(
+            parse_activity_report(html).unwrap(),
+            [
+                line("Assistance", "Food", Some(1204), Some(1840.0)),
+                line("Assistance", "Home visits", Some(12), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn unexpected_pages_are_page_changed() {
+        for html in [
+            "<p>No report</p>",
+            "<table><tr><td>Requests</td><td>7</td><td>$70</td></tr></table>",
+            "<table><tr><th>Count</th><th>Amount</th></tr></table>",
+            "<table><tr><th>Description</th><th>Notes</th></tr></table>",
+        ] {
+            let err = parse_activity_report(html).unwrap_err();
+            assert!(
+                matches!(err, ServWareError::PageChanged(_)),
+                "{html}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn missing_column_leaves_the_field_empty() {
+        let html = "<table>
+            <tr><th>Description</th><th>Amount</th></tr>
+            <tr><td>Food</td><td>12</td></tr>
+        </table>";
+        assert_eq!(
+            parse_activity_report(html).unwrap(),
+            [line("", "Food", None, Some(12.0))]
+        );
+    }
+
+    #[test]
+    fn empty_table_has_no_rows() {
+        let html = "<table><tr><th>Description</th><th>Count</th></tr></table>";
+        assert!(parse_activity_report(html).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,210 @@
+use scraper::Html;
+use scraper::Selector;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::error::ResultExt;
+use super::scrape;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// One step of a request's approval chain.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Approval {
+    /// Who approved, or is asked to approve, the request.
+    pub approver: String,
+    /// Status as ServWare shows it, e.g. `"Approved"` or `"Pending"`.
+    pub status: String,
+    pub date: String,
+    pub notes: String,
+}
+
+impl Approval {
+    /// Whether the status is exactly `"Approved"`, ignoring case and
+    /// surrounding space. `"Not Approved"`, `"Disapproved"` and the like
+    /// are not.
+    pub fn is_approved(&self) -> bool {
+        self.status.trim().eq_ignore_ascii_case("approved")
+    }
+}
+
+/// Which [`Approval`] field a column of the approval table holds.
+#[derive(Clone, Copy)]
+enum Column {
+    Approver,
+    Status,
+    Date,
+    Notes,
+    Other,
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Fetch a request's approval chain: who has approved it and what is
+    /// still pending. Empty for requests that need no approval.
+    pub async fn fetch_approvals(&self, request_id: u64) -> Result<Vec<Approval>> {
+        let url = self.approvals_url(request_id);
+        tracing::debug!(%url, "fetching approval list");
+
+        let response = self
+            .send(|client| {
+                client
+                    .get(&url)
+                    .header("X-Requested-With", "XMLHttpRequest")
+            })
+            .await
+            .context("fetch approvals HTTP request failed")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ServWareError::status("fetch approvals", status));
+        }
+
+        let html = response
+            .text()
+            .await
+            .context("failed to read approval list body")?;
+
+        let approvals = self::parse_approvals(&html);
+        tracing::debug!(count = approvals.len(), "parsed approval list");
+        Ok(approvals)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Read the approval table, matching columns by their header text. Without
+/// a header row, columns are taken as approver, status, date, notes.
+fn parse_approvals(html: &str) -> Vec<Approval> {
+    let fragment = Html::parse_fragment(html);
+    let rows = Selector::parse("tr").expect("valid CSS selector");
+    let headers = Selector::parse("th").expect("valid CSS selector");
+    let cells = Selector::parse("td").expect("valid CSS selector");
+
+    let mut columns = vec![
+        Column::Approver,
+        Column::Status,
+        Column::Date,
+        Column::Notes,
+    ];
+    let mut approvals = Vec::new();
+    for row in fragment.select(&rows) {
+        let header: Vec<String> = row.select(&headers).map(scrape::cell_text).collect();
+        if !header.is_empty() {
+            columns = header.iter().map(|h| self::column(h)).collect();
+            continue;
+        }
+
+        let values: Vec<String> = row.select(&cells).map(scrape::cell_text).collect();
+        // Skips placeholder rows such as "No approvals required".
+        if values.len() < 2 {
+            continue;
+        }
+
+        let mut approval = Approval::default();
+        for (column, value) in columns.iter().zip(values) {
+            match column {
+                Column::Approver => approval.approver = value,
+                Column::Status => approval.status = value,
+                Column::Date => approval.date = value,
+                Column::Notes => approval.notes = value,
+                Column::Other => {}
+            }
+        }
+        approvals.push(approval);
+    }
+    approvals
+}
+
+fn column(header: &str) -> Column {
+    let header = header.to_lowercase();
+    if header.contains("status") {
+        Column::Status
+    } else if header.contains("date") {
+        Column::Date
+    } else if header.contains("note") || header.contains("comment") {
+        Column::Notes
+    } else if ["approv", "member", "name", "user"]
+        .iter()
+        .any(|word| header.contains(word))
+    {
+        Column::Approver
+    } else {
+        Column::Other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Approval;
+    use super::parse_approvals;
+
+    #[test]
+    fn reads_columns_by_header_text() {
+        let html = "<table>
+            <tr><th>Status</th><th>Approver</th><th>Comments</th><th>Date</th></tr>
+            <tr><td>Approved</td><td>Jane Doe</td><td>ok</td><td>03/02/2026</td></tr>
+            <tr><td>Pending</td><td>Bob Roe</td><td></td><td></td></tr>
+        </table>";
+
+        let approvals = parse_approvals(html);
+        assert_eq!(
+            approvals[0],
+            Approval {
+                approver: "Jane Doe".into(),
+                status: "Approved".into(),
+                date: "03/02/2026".into(),
+                notes: "ok".into(),
+            }
+        );
+        assert_eq!(approvals[1].approver, "Bob Roe");
+        assert!(!approvals[1].is_approved());
+    }
+
+    #[test]
+    fn only_an_approved_status_counts_as_approved() {
+        let html = "<table>
+            <tr><th>Approver</th><th>Status</th></tr>
+            <tr><td>A</td><td> APPROVED </td></tr>
+            <tr><td>B</td><td>Not Approved</td></tr>
+            <tr><td>C</td><td>Disapproved</td></tr>
+            <tr><td>D</td><td>Unapproved</td></tr>
+            <tr><td>E</td><td>Approved?</td></tr>
+        </table>";
+
+        let approved: Vec<bool> = parse_approvals(html)
+            .iter()
+            .map(Approval::is_approved)
+            .collect();
+        assert_eq!(approved, [true, false, false, false, false]);
+    }
+
+    #[test]
+    fn placeholder_row_is_no_approvals() {
+        let html = r#"<table><tr><th>Approver</th><th>Status</th></tr>
+            <tr><td colspan="2">No approvals required</td></tr></table>"#;
+        assert!(parse_approvals(html).is_empty());
+        assert!(parse_approvals("").is_empty());
+    }
+
+    #[test]
+    fn missing_column_leaves_the_field_empty() {
+        let html = "<table>
+            <tr><th>Approver</th><th>Status</th></tr>
+            <tr><td>Jane Doe</td><td>Approved</td></tr>
+        </table>";
+        let approvals = parse_approvals(html);
+        assert_eq!(approvals[0].status, "Approved");
+        assert_eq!(approvals[0].date, "");
+    }
+}
@@ -0,0 +1,178 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Utc;
+use reqwest::Url;
+use serde::Deserialize;
+use serde::Deserializer;
+
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::error::ResultExt;
+
+/// How calendar event times are written, e.g. `2026-02-03T00:00:00.000-0800`.
+const EVENT_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f%z";
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// The calendars ServWare shows on its calendar page (api.md §9).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarFeed {
+    HomeVisits,
+    FollowUps,
+    ConferenceEvents,
+}
+
+impl CalendarFeed {
+    fn path(self) -> &'static str {
+        match self {
+            CalendarFeed::HomeVisits => "homevisits",
+            CalendarFeed::FollowUps => "followups",
+            CalendarFeed::ConferenceEvents => "conferenceevents",
+        }
+    }
+}
+
+/// One event from a calendar feed.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarEvent {
+    /// Display text, e.g. `"Home Visit: Doe, Jane (Volunteer Name)"`.
+    pub title: String,
+    #[serde(deserialize_with = "self::de_event_time")]
+    pub start: DateTime<FixedOffset>,
+    #[serde(default, deserialize_with = "self::de_opt_event_time")]
+    pub end: Option<DateTime<FixedOffset>>,
+    /// Path of the linked page, e.g. `/app/assistancerequests/3724974`.
+    #[serde(default)]
+    pub url: String,
+    /// The assistance request the event links to, parsed from `url`.
+    #[serde(skip)]
+    pub request_id: Option<u64>,
+    /// `"calevent"` for conference events, `None` for visits.
+    #[serde(default)]
+    pub event_type: Option<String>,
+    #[serde(default)]
+    pub color_code: Option<String>,
+    #[serde(default)]
+    pub text_color_code: Option<String>,
+    #[serde(default)]
+    pub all_day: bool,
+    #[serde(default)]
+    pub editable: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Events on one of the ServWare calendars between `start` and `end`.
+    pub async fn fetch_calendar(
+        &self,
+        feed: CalendarFeed,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<CalendarEvent>> {
+        let url = Url::parse_with_params(
+            &self.calendar_url(feed.path()),
+            [
+                ("start", start.timestamp().to_string()),
+                ("end", end.timestamp().to_string()),
+                ("_", Utc::now().timestamp_millis().to_string()),
+            ],
+        )
+        .context("failed to build calendar URL")?;
+        tracing::debug!(%url, ?feed, "fetching calendar events");
+
+        let response = self
+            .send(|client| {
+                client
+                    .get(url.clone())
+                    .header("X-Requested-With", "XMLHttpRequest")
+                    .header("Accept", "application/json, text/javascript, */*; q=0.01")
+            })
+            .await
+            .context("fetch calendar HTTP request failed")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ServWareError::status("fetch calendar", status));
+        }
+
+        let mut events: Vec<CalendarEvent> = response
+            .json()
+            .await
+            .context("failed to deserialize calendar events")?;
+        for event in &mut events {
+            event.request_id = self::request_id_from_url(&event.url);
+        }
+
+        tracing::debug!(count = events.len(), "fetched calendar events");
+        Ok(events)
+    }
+
+    /// Scheduled home visits between `start` and `end`.
+    pub async fn fetch_home_visits(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<CalendarEvent>> {
+        self.fetch_calendar(CalendarFeed::HomeVisits, start, end)
+            .await
+    }
+
+    /// Follow-ups due between `start` and `end`.
+    pub async fn fetch_follow_up_events(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<CalendarEvent>> {
+        self.fetch_calendar(CalendarFeed::FollowUps, start, end)
+            .await
+    }
+
+    /// Conference events (meetings and the like) between `start` and `end`.
+    pub async fn fetch_conference_events(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<CalendarEvent>> {
+        self.fetch_calendar(CalendarFeed::ConferenceEvents, start, end)
+            .await
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// `/app/assistancerequests/3724974` → `Some(3724974)`.
+fn request_id_from_url(url: &str) -> Option<u64> {
+    url.trim_end_matches('/')
+        .strip_prefix("/app/assistancerequests/")?
+        .parse()
+        .ok()
+}
+
+fn de_event_time<'de, D: Deserializer<'de>>(d: D) -> Result<DateTime<FixedOffset>, D::Error> {
+    let raw = String::deserialize(d)?;
+    DateTime::parse_from_str(&raw, EVENT_TIME_FORMAT)
+        .or_else(|_| DateTime::parse_from_rfc3339(&raw))
+        .map_err(serde::de::Error::custom)
+}
+
+fn de_opt_event_time<'de, D: Deserializer<'de>>(
+    d: D,
+) -> Result<Option<DateTime<FixedOffset>>, D::Error> {
+    let raw: Option<String> = Option::deserialize(d)?;
+    match raw.as_deref() {
+        None | Some("") => Ok(None),
+        Some(raw) => DateTime::parse_from_str(raw, EVENT_TIME_FORMAT)
+            .or_else(|_| DateTime::parse_from_rfc3339(raw))
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+    }
+}
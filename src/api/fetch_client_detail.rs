@@ -0,0 +1,119 @@
+use scraper::Html;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::scrape;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Every field of the neighbor edit form, as rendered on the client detail
+/// page.
+///
+/// Values are kept as the form holds them, so an empty string means "not
+/// set".
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClientDetail {
+    pub id: u64,
+
+    // Name
+    pub first_name: String,
+    pub last_name: String,
+    pub middle_initial: String,
+    pub birth_date: String,
+
+    // Contact
+    pub home_phone: String,
+    pub work_phone: String,
+    pub mobile_phone: String,
+    pub email_address: String,
+    pub text_communication_preferred: bool,
+
+    // Address
+    pub street_address_line1: String,
+    pub street_address_line2: String,
+    pub city: String,
+    pub state_code: String,
+    pub postal_code: String,
+
+    // Status checkboxes
+    pub parishioner: bool,
+    pub homeless: bool,
+    pub disabled_client: bool,
+    pub veteran: bool,
+    pub private_client: bool,
+
+    // Notes
+    pub notes: String,
+    pub alert_note: String,
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Fetch the full edit form of a neighbor from their detail page.
+    pub async fn fetch_client_detail(&self, client_id: u64) -> Result<ClientDetail> {
+        let html = self
+            .fetch_page(&self.client_url(client_id), "client detail page")
+            .await?;
+
+        self::parse_client_detail(client_id, &html)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// CSS selector for the edit form on a neighbor's detail page, which posts
+/// back to the page itself.
+pub(crate) fn client_form(id: u64) -> String {
+    format!(r#"form[action$="/app/clients/{id}"]"#)
+}
+
+fn parse_client_detail(id: u64, html: &str) -> Result<ClientDetail> {
+    let document = Html::parse_document(html);
+    let fields = scrape::form_values(&document, &self::client_form(id))
+        .filter(|fields| scrape::form_value(fields, "lastName").is_some())
+        .ok_or_else(|| {
+            ServWareError::PageChanged(format!("no client form found for client {id}"))
+        })?;
+
+    let text = |name: &str| {
+        scrape::form_value(&fields, name)
+            .unwrap_or_default()
+            .to_string()
+    };
+    let checked = |name: &str| scrape::form_value(&fields, name) == Some("true");
+
+    Ok(ClientDetail {
+        id,
+        first_name: text("firstName"),
+        last_name: text("lastName"),
+        middle_initial: text("middleInitial"),
+        birth_date: text("birthDate"),
+        home_phone: text("homePhone"),
+        work_phone: text("workPhone"),
+        mobile_phone: text("mobilePhone"),
+        email_address: text("emailAddress"),
+        text_communication_preferred: checked("textCommunicationPreferred"),
+        street_address_line1: text("streetAddressLine1"),
+        street_address_line2: text("streetAddressLine2"),
+        city: text("city"),
+        state_code: text("stateCode"),
+        postal_code: text("postalCode"),
+        parishioner: checked("parishioner"),
+        homeless: checked("homeless"),
+        disabled_client: checked("disabledClient"),
+        veteran: checked("veteran"),
+        private_client: checked("privateClient"),
+        notes: text("notes"),
+        alert_note: text("alertNote"),
+    })
+}
@@ -0,0 +1,125 @@
+use futures_util::Stream;
+use futures_util::TryStreamExt;
+
+use super::Result;
+use super::ServWare;
+use super::datatables;
+use super::datatables::DataTablesResponse;
+use super::fetch_requests::Client;
+
+// ---------------------------------------------------------------------------
+// Response types
+// ---------------------------------------------------------------------------
+
+/// DataTables envelope of the neighbors list.
+pub type FetchClientsResponse = DataTablesResponse<Client>;
+
+// ---------------------------------------------------------------------------
+// Query parameters
+// ---------------------------------------------------------------------------
+
+/// Parameters for listing neighbors.
+#[derive(Debug, Clone)]
+pub struct FetchClientsParams {
+    pub display_start: u32,
+    pub display_length: u32,
+    pub sort_col: u32,
+    pub sort_dir: String,
+    /// ServWare's global search: matches names, phone numbers and
+    /// addresses.
+    pub search: String,
+}
+
+impl FetchClientsParams {
+    /// Records requested per page unless the caller picks another size.
+    pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
+    /// Config for listing every neighbor by last name.
+    pub fn new_all() -> Self {
+        Self {
+            display_start: 0,
+            display_length: Self::DEFAULT_PAGE_SIZE,
+            sort_col: 1,
+            sort_dir: "asc".into(),
+            search: String::new(),
+        }
+    }
+
+    /// Config for neighbors matching `query`, e.g. `"Lopez"`,
+    /// `"650-555-0100"` or `"1 Main St"`.
+    pub fn new_search(query: &str) -> Self {
+        Self {
+            search: query.trim().to_string(),
+            ..Self::new_all()
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Fetch one page of neighbors from the DataTables list behind
+    /// `/app/clients`.
+    pub async fn fetch_clients(&self, params: &FetchClientsParams) -> Result<FetchClientsResponse> {
+        let sort_col = params.sort_col.to_string();
+        let url = datatables::page_url(
+            &self.clients_url(),
+            &[
+                "id",
+                "lastName",
+                "firstName",
+                "streetAddressLine1",
+                "city",
+                "homePhone",
+                "mobilePhone",
+                "lastRequestDate",
+            ],
+            params.display_start,
+            params.display_length,
+            &[
+                ("iSortCol_0", &sort_col),
+                ("sSortDir_0", &params.sort_dir),
+                ("iSortingCols", "1"),
+                ("bSortable_0", "false"),
+                ("bSortable_1", "true"),
+                ("bSortable_2", "true"),
+                ("bSortable_3", "false"),
+                ("bSortable_4", "true"),
+                ("bSortable_5", "false"),
+                ("bSortable_6", "false"),
+                ("bSortable_7", "true"),
+                ("sSearch", &params.search),
+                ("bRegex", "false"),
+            ],
+        )?;
+
+        self.fetch_data_table(url, "clients").await
+    }
+
+    /// Yield every neighbor matching `params`, one page at a time, the same
+    /// way [`stream_requests`](super::ServWareApi::stream_requests) walks
+    /// the request list.
+    pub fn stream_clients(
+        &self,
+        params: FetchClientsParams,
+    ) -> impl Stream<Item = Result<Client>> + Send + '_ {
+        let (start, length) = (params.display_start, params.display_length);
+        datatables::paginate(start, length, move |display_start| {
+            let params = FetchClientsParams {
+                display_start,
+                ..params.clone()
+            };
+            async move { self.fetch_clients(&params).await }
+        })
+    }
+
+    /// Every neighbor whose name, phone number or address matches `query`,
+    /// e.g. to look up a walk-in neighbor who has no open request.
+    pub async fn search_clients(&self, query: &str) -> Result<Vec<Client>> {
+        self.stream_clients(FetchClientsParams::new_search(query))
+            .try_collect()
+            .await
+    }
+}
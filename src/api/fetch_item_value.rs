@@ -0,0 +1,156 @@
+use reqwest::Url;
+
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::error::ResultExt;
+
+/// Guesses at the key holding the amount in an `itemvalue` response object,
+/// most specific first. api.md only calls the response "item value info"
+/// and none of these has been checked against a live response, so
+/// [`ItemValue::from_json`] logs any payload none of them matches.
+const VALUE_KEYS: [&str; 5] = [
+    "monetaryValue",
+    "itemValue",
+    "defaultValue",
+    "value",
+    "amount",
+];
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// The default value ServWare suggests for an assistance type and client.
+#[derive(Debug, Clone, Default)]
+pub struct ItemValue {
+    /// Suggested monetary value, or `None` if no default is configured.
+    pub monetary_value: Option<f64>,
+    /// The response as ServWare sent it, for fields not mapped above.
+    pub raw: serde_json::Value,
+}
+
+impl ItemValue {
+    /// Read the amount out of an `itemvalue` response.
+    ///
+    /// api.md only describes the response as "item value info", so this
+    /// accepts a bare number or numeric string as well as an object holding
+    /// one under any of the guessed [`VALUE_KEYS`]. Anything else is logged
+    /// at `warn` with the payload, so a wrong guess shows up in the logs
+    /// rather than as a quietly missing value.
+    pub fn from_json(raw: serde_json::Value) -> Self {
+        let monetary_value = match &raw {
+            serde_json::Value::Object(fields) => VALUE_KEYS
+                .iter()
+                .find_map(|key| fields.get(*key).and_then(self::as_amount)),
+            other => self::as_amount(other),
+        };
+        if monetary_value.is_none() && !raw.is_null() {
+            tracing::warn!(%raw, "no amount found in item value response (tried {VALUE_KEYS:?})");
+        }
+        Self {
+            monetary_value,
+            raw,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Look up the default monetary value for an assistance type and client,
+    /// as the "add assistance item" form does when a type is picked.
+    pub async fn fetch_item_value(
+        &self,
+        assistance_type_id: &str,
+        client_id: u64,
+    ) -> Result<ItemValue> {
+        let url = Url::parse_with_params(
+            &self.item_value_url(),
+            [
+                ("selectid", assistance_type_id.to_string()),
+                ("clientid", client_id.to_string()),
+            ],
+        )
+        .context("failed to build item value URL")?;
+        tracing::debug!(%url, "fetching item value");
+
+        let response = self
+            .send(|client| {
+                client
+                    .get(url.clone())
+                    .header("X-Requested-With", "XMLHttpRequest")
+                    .header("Accept", "application/json, text/javascript, */*; q=0.01")
+            })
+            .await
+            .context("fetch item value HTTP request failed")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ServWareError::status("fetch item value", status));
+        }
+
+        let body = response
+            .text()
+            .await
+            .context("failed to read item value response")?;
+        if body.trim().is_empty() {
+            return Ok(ItemValue::default());
+        }
+        let raw: serde_json::Value =
+            serde_json::from_str(&body).context("failed to parse item value response as JSON")?;
+
+        Ok(ItemValue::from_json(raw))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn as_amount(value: &serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::Number(n) => n.as_f64(),
+        serde_json::Value::String(s) => s.trim().trim_start_matches('$').parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::ItemValue;
+
+    #[test]
+    fn reads_bare_numbers_and_strings() {
+        assert_eq!(ItemValue::from_json(json!(60)).monetary_value, Some(60.0));
+        assert_eq!(
+            ItemValue::from_json(json!(" $65.50")).monetary_value,
+            Some(65.5)
+        );
+    }
+
+    #[test]
+    fn prefers_the_most_specific_key() {
+        let raw = json!({ "value": 1, "monetaryValue": "60.00", "amount": 2 });
+        assert_eq!(ItemValue::from_json(raw).monetary_value, Some(60.0));
+    }
+
+    #[test]
+    fn skips_keys_that_are_not_amounts() {
+        let raw = json!({ "monetaryValue": null, "itemValue": "n/a", "value": 45 });
+        assert_eq!(ItemValue::from_json(raw).monetary_value, Some(45.0));
+    }
+
+    #[test]
+    fn unknown_shapes_keep_the_raw_payload() {
+        let raw = json!({ "price": 60 });
+        let value = ItemValue::from_json(raw.clone());
+        assert_eq!(value.monetary_value, None);
+        assert_eq!(value.raw, raw);
+        assert_eq!(ItemValue::from_json(json!(null)).monetary_value, None);
+    }
+}
@@ -0,0 +1,105 @@
+use scraper::Html;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::scrape;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// One entry of a ServWare dropdown: the ID the forms expect and the label
+/// shown next to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LookupOption {
+    pub id: String,
+    pub name: String,
+}
+
+/// The conference-specific IDs needed to fill in request and assistance
+/// item forms.
+#[derive(Debug, Clone, Default)]
+pub struct Lookups {
+    /// Values for `UpdateAssistanceInput::assistance_type_id`.
+    pub assistance_types: Vec<LookupOption>,
+    /// Values for `UpdateRequestInput::denial_reason_id`.
+    pub denial_reasons: Vec<LookupOption>,
+    /// Values for `UpdateRequestInput::referred_from_organization_id`.
+    pub referral_organizations: Vec<LookupOption>,
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Fetch every lookup table, reading the dropdowns of one request's
+    /// detail page and new-assistance-item form.
+    pub async fn fetch_lookups(&self, request_id: u64) -> Result<Lookups> {
+        let detail = self
+            .fetch_page(&self.request_url(request_id), "request detail page")
+            .await?;
+        let item_form = self
+            .fetch_page(
+                &self.assistance_item_url(request_id),
+                "new assistance item form",
+            )
+            .await?;
+
+        let detail = Html::parse_document(&detail);
+        let item_form = Html::parse_document(&item_form);
+        Ok(Lookups {
+            assistance_types: self::lookup(&item_form, "assistanceTypeId")?,
+            denial_reasons: self::lookup(&detail, "denialReasonId")?,
+            referral_organizations: self::lookup(&detail, "referredFromOrganizationId")?,
+        })
+    }
+
+    /// Assistance types, from the `assistanceTypeId` dropdown of the new
+    /// assistance item form.
+    pub async fn fetch_assistance_types(&self, request_id: u64) -> Result<Vec<LookupOption>> {
+        let html = self
+            .fetch_page(
+                &self.assistance_item_url(request_id),
+                "new assistance item form",
+            )
+            .await?;
+        self::lookup(&Html::parse_document(&html), "assistanceTypeId")
+    }
+
+    /// Denial reasons, from the `denialReasonId` dropdown of the request
+    /// detail page.
+    pub async fn fetch_denial_reasons(&self, request_id: u64) -> Result<Vec<LookupOption>> {
+        let html = self
+            .fetch_page(&self.request_url(request_id), "request detail page")
+            .await?;
+        self::lookup(&Html::parse_document(&html), "denialReasonId")
+    }
+
+    /// Referral organizations, from the `referredFromOrganizationId`
+    /// dropdown of the request detail page.
+    pub async fn fetch_referral_organizations(&self, request_id: u64) -> Result<Vec<LookupOption>> {
+        let html = self
+            .fetch_page(&self.request_url(request_id), "request detail page")
+            .await?;
+        self::lookup(&Html::parse_document(&html), "referredFromOrganizationId")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn lookup(document: &Html, select_id: &str) -> Result<Vec<LookupOption>> {
+    let options = scrape::select_options(document, select_id)
+        .ok_or_else(|| ServWareError::PageChanged(format!("no {select_id} dropdown found")))?;
+
+    tracing::debug!(count = options.len(), select_id, "scraped lookup options");
+    Ok(options
+        .into_iter()
+        .map(|(id, name)| LookupOption { id, name })
+        .collect())
+}
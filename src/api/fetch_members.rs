@@ -1,18 +1,18 @@
-use anyhow::Context;
-use anyhow::ensure;
 use scraper::Html;
-use scraper::Selector;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::Result;
 use super::ServWare;
+use super::ServWareError;
+use super::scrape;
 
 // ---------------------------------------------------------------------------
 // Types
 // ---------------------------------------------------------------------------
 
 /// A volunteer member scraped from the request detail page.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Member {
     pub id: String,
     pub name: String,
@@ -29,48 +29,23 @@ impl ServWare {
     /// available as `<option>` elements inside the
     /// `<select id="requestAssignedToMemberId">` dropdown on the request
     /// detail HTML page.
-    pub async fn fetch_members(&self, request_id: u64) -> anyhow::Result<Vec<Member>> {
-        let url = Self::request_url(request_id);
-        tracing::debug!(%url, "fetching request detail page for member list");
-
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .context("failed to fetch request detail page")?;
-
-        let status = response.status();
-        if !status.is_success() && !status.is_redirection() {
-            anyhow::bail!("fetch request detail page failed with status {status}");
-        }
-
-        let html = response
-            .text()
-            .await
-            .context("failed to read request detail page body")?;
+    pub async fn fetch_members(&self, request_id: u64) -> Result<Vec<Member>> {
+        let html = self
+            .fetch_page(&self.request_url(request_id), "request detail page")
+            .await?;
 
         let document = Html::parse_document(&html);
-
-        let select_selector = Selector::parse(r#"select#requestAssignedToMemberId option"#)
-            .expect("valid CSS selector");
-
-        let members: Vec<Member> = document
-            .select(&select_selector)
-            .filter_map(|el| {
-                let value = el.value().attr("value")?.trim().to_string();
-                if value.is_empty() {
-                    return None; // skip "-- Select --" placeholder
-                }
-                let name = el.text().collect::<String>().trim().to_string();
-                Some(Member { id: value, name })
-            })
+        let members: Vec<Member> = scrape::select_options(&document, "requestAssignedToMemberId")
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(id, name)| Member { id, name })
             .collect();
 
-        ensure!(
-            !members.is_empty(),
-            "no members found in request {request_id} — page structure may have changed"
-        );
+        if members.is_empty() {
+            return Err(ServWareError::PageChanged(format!(
+                "no members found in request {request_id}"
+            )));
+        }
 
         tracing::debug!(count = members.len(), "scraped member list");
         Ok(members)
@@ -0,0 +1,137 @@
+use scraper::Html;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::scrape;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Every field of the request edit form, as rendered on the request detail
+/// page.
+///
+/// Unlike [`AssistanceRequest`](super::fetch_requests::AssistanceRequest),
+/// this carries the numeric IDs behind the member, denial reason, and
+/// referral organization dropdowns. Values are kept as the form holds them,
+/// so an empty string means "not set".
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RequestDetail {
+    pub id: u64,
+
+    // Status
+    pub status: String,
+    pub denial_reason_id: String,
+    pub denial_reason_str: String,
+
+    // Client info
+    pub client_first_name: String,
+    pub client_last_name: String,
+    pub date_requested: String,
+
+    // Assignment
+    pub request_assigned_to_member_id: String,
+    pub request_note: String,
+
+    // Visit type checkboxes
+    pub home_visit_required: bool,
+    pub other_visit: bool,
+    pub elder_care_visit: bool,
+    pub hospital_visit: bool,
+    pub prison_visit: bool,
+    pub telephone_visit: bool,
+    pub church_pantry_visit: bool,
+
+    // Visit details
+    pub home_visit_cnt: String,
+    pub visit_completed: bool,
+    pub visit_assigned_to_member_id: String,
+    pub visit_assigned_to_member_id_secondary: String,
+    pub visit_mileage_in_service: String,
+    pub visit_hours_in_service: String,
+    pub visit_scheduled_date: String,
+    pub visit_scheduled_time: String,
+    pub people_helped_override: String,
+    pub visit_notes: String,
+
+    // Referral
+    pub referred_to_agency: bool,
+    pub referred_to_conference: bool,
+    pub referred_from_organization_id: String,
+    pub referral_note: String,
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Fetch the full edit form of a request from its detail page.
+    ///
+    /// This is the only place ServWare exposes the selected member IDs; the
+    /// list API returns display names instead.
+    pub async fn fetch_request_detail(&self, request_id: u64) -> Result<RequestDetail> {
+        let html = self
+            .fetch_page(&self.request_url(request_id), "request detail page")
+            .await?;
+
+        self::parse_request_detail(request_id, &html)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn parse_request_detail(id: u64, html: &str) -> Result<RequestDetail> {
+    let document = Html::parse_document(html);
+    let form = format!(r#"form[action$="/app/assistancerequests/{id}"]"#);
+    let fields = scrape::form_values(&document, &form)
+        .filter(|fields| scrape::form_value(fields, "status").is_some())
+        .ok_or_else(|| {
+            ServWareError::PageChanged(format!("no request form found for request {id}"))
+        })?;
+
+    let text = |name: &str| {
+        scrape::form_value(&fields, name)
+            .unwrap_or_default()
+            .to_string()
+    };
+    let checked = |name: &str| scrape::form_value(&fields, name) == Some("true");
+
+    Ok(RequestDetail {
+        id,
+        status: text("status"),
+        denial_reason_id: text("denialReasonId"),
+        denial_reason_str: text("denialReasonStr"),
+        client_first_name: text("clientFirstName"),
+        client_last_name: text("clientLastName"),
+        date_requested: text("dateRequested"),
+        request_assigned_to_member_id: text("requestAssignedToMemberId"),
+        request_note: text("requestNote"),
+        home_visit_required: checked("homeVisitRequired"),
+        other_visit: checked("otherVisit"),
+        elder_care_visit: checked("elderCareVisit"),
+        hospital_visit: checked("hospitalVisit"),
+        prison_visit: checked("prisonVisit"),
+        telephone_visit: checked("telephoneVisit"),
+        church_pantry_visit: checked("churchPantryVisit"),
+        home_visit_cnt: text("homeVisitCnt"),
+        visit_completed: checked("visitCompleted"),
+        visit_assigned_to_member_id: text("visitAssignedToMemberId"),
+        visit_assigned_to_member_id_secondary: text("visitAssignedToMemberIdSecondary"),
+        visit_mileage_in_service: text("visitMileageInService"),
+        visit_hours_in_service: text("visitHoursInService"),
+        visit_scheduled_date: text("visitScheduledDate"),
+        visit_scheduled_time: text("visitScheduledTime"),
+        people_helped_override: text("peopleHelpedOverride"),
+        visit_notes: text("visitNotes"),
+        referred_to_agency: checked("referredToAgency"),
+        referred_to_conference: checked("referredToConference"),
+        referred_from_organization_id: text("referredFromOrganizationId"),
+        referral_note: text("referralNote"),
+    })
+}
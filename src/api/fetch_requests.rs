@@ -1,31 +1,19 @@
-use std::time::SystemTime;
-use std::time::UNIX_EPOCH;
-
-use anyhow::Context;
-use reqwest::Url;
 use serde::Deserialize;
 
+use super::Result;
 use super::ServWare;
+use super::datatables;
+use super::datatables::DataTablesResponse;
+use super::follow_ups::FollowUp;
 
 // ---------------------------------------------------------------------------
 // Response types
 // ---------------------------------------------------------------------------
 
 /// DataTables server-side processing response envelope.
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct FetchRequestsResponse {
-    #[serde(rename = "sEcho")]
-    pub s_echo: u32,
-    #[serde(rename = "iTotalRecords")]
-    pub i_total_records: u32,
-    #[serde(rename = "iTotalDisplayRecords")]
-    pub i_total_display_records: u32,
-    #[serde(rename = "aaData")]
-    pub aa_data: Vec<AssistanceRequest>,
-}
+pub type FetchRequestsResponse = DataTablesResponse<AssistanceRequest>;
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
 pub struct AssistanceRequest {
     // --- Metadata ---
@@ -128,7 +116,7 @@ pub struct AssistanceRequest {
     pub pending_items: Option<serde_json::Value>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
 pub struct Client {
     pub id: u64,
@@ -171,13 +159,32 @@ pub struct Client {
     pub last_request_date: String,
     pub assigned_member: Option<String>,
     pub open_follow_up: bool,
+    /// Kept as raw JSON so one odd follow-up can't fail the whole request
+    /// list; [`open_follow_ups`](Self::open_follow_ups) parses them.
     pub follow_ups: Vec<serde_json::Value>,
 
     // --- Nested ---
     pub conference: serde_json::Value,
 }
 
-#[derive(Debug, Default, Deserialize)]
+impl Client {
+    /// Follow-ups on this neighbor that haven't been completed. Entries
+    /// that don't parse as a [`FollowUp`] are logged and skipped.
+    pub fn open_follow_ups(&self) -> impl Iterator<Item = FollowUp> + '_ {
+        self.follow_ups
+            .iter()
+            .filter_map(|raw| match FollowUp::deserialize(raw) {
+                Ok(follow_up) => Some(follow_up),
+                Err(e) => {
+                    tracing::warn!(client_id = self.id, %raw, "skipping unreadable follow-up: {e}");
+                    None
+                }
+            })
+            .filter(|f| !f.completed)
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
 pub struct AssistanceItem {
     pub id: u64,
@@ -200,7 +207,7 @@ pub struct AssistanceItem {
     pub assistance_type: AssistanceType,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
 pub struct AssistanceType {
     pub id: u64,
@@ -218,6 +225,7 @@ pub struct AssistanceType {
 // ---------------------------------------------------------------------------
 
 /// Parameters for fetching assistance requests.
+#[derive(Debug, Clone)]
 pub struct FetchRequestsParams {
     pub display_start: u32,
     pub display_length: u32,
@@ -231,12 +239,15 @@ pub struct FetchRequestsParams {
 }
 
 impl FetchRequestsParams {
+    /// Records requested per page unless the caller picks another size.
+    pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
     /// Config for fetching "Open" status requests in
     /// ascending order by date.
     pub fn new_open_asc() -> Self {
         Self {
             display_start: 0,
-            display_length: 100,
+            display_length: Self::DEFAULT_PAGE_SIZE,
             sort_col: 3,
             sort_dir: "asc".into(),
             search: String::new(),
@@ -246,6 +257,18 @@ impl FetchRequestsParams {
             filter_by_visit_assigned: String::new(),
         }
     }
+
+    /// Config for finding one request by ID, whatever its status.
+    ///
+    /// Uses the global search box, which matches request IDs among other
+    /// columns, so results still have to be checked for an exact ID match.
+    pub fn new_by_id(id: u64) -> Self {
+        Self {
+            search: id.to_string(),
+            filter_by_status: String::new(),
+            ..Self::new_open_asc()
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -257,108 +280,52 @@ impl ServWare {
     pub async fn fetch_requests(
         &self,
         params: &FetchRequestsParams,
-    ) -> anyhow::Result<FetchRequestsResponse> {
-        let url = Self::list_url();
-
-        let cache_buster = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis()
-            .to_string();
-
-        let s_columns = "id,id,status,dateRequested,client.lastName,client.firstName,\
-                         requestAssignedToMember,streetAddressLine1,client.homePhone,\
-                         client.mobilePhone,pendingItems,id";
-
-        let display_start = params.display_start.to_string();
-        let display_length = params.display_length.to_string();
+    ) -> Result<FetchRequestsResponse> {
         let sort_col = params.sort_col.to_string();
-
-        let query_pairs: &[(&str, &str)] = &[
-            ("sEcho", "1"),
-            ("iColumns", "12"),
-            ("sColumns", s_columns),
-            ("iDisplayStart", &display_start),
-            ("iDisplayLength", &display_length),
-            ("mDataProp_0", "id"),
-            ("mDataProp_1", "id"),
-            ("mDataProp_2", "status"),
-            ("mDataProp_3", "dateRequested"),
-            ("mDataProp_4", "client.lastName"),
-            ("mDataProp_5", "client.firstName"),
-            ("mDataProp_6", "requestAssignedToMember"),
-            ("mDataProp_7", "streetAddressLine1"),
-            ("mDataProp_8", "client.homePhone"),
-            ("mDataProp_9", "client.mobilePhone"),
-            ("mDataProp_10", "id"),
-            ("mDataProp_11", "id"),
-            ("iSortCol_0", &sort_col),
-            ("sSortDir_0", &params.sort_dir),
-            ("iSortingCols", "1"),
-            ("bSortable_0", "false"),
-            ("bSortable_1", "false"),
-            ("bSortable_2", "true"),
-            ("bSortable_3", "true"),
-            ("bSortable_4", "true"),
-            ("bSortable_5", "true"),
-            ("bSortable_6", "false"),
-            ("bSortable_7", "false"),
-            ("bSortable_8", "false"),
-            ("bSortable_9", "false"),
-            ("bSortable_10", "false"),
-            ("bSortable_11", "false"),
-            ("sSearch", &params.search),
-            ("bRegex", "false"),
-            ("filterByStatus", &params.filter_by_status),
-            ("filterByPartnerConf", &params.filter_by_partner_conf),
-            ("filterByReqAssigned", &params.filter_by_req_assigned),
-            ("filterByVisitAssigned", &params.filter_by_visit_assigned),
-            ("_", &cache_buster),
-        ];
-
-        let mut full_url = Url::parse(&url).context("failed to parse list URL")?;
-        {
-            let mut qs = full_url.query_pairs_mut();
-            for &(k, v) in query_pairs {
-                qs.append_pair(k, v);
-            }
-        }
-
-        tracing::debug!(%full_url, "fetching assistance requests");
-
-        let response = self
-            .client
-            .get(full_url)
-            .header("X-Requested-With", "XMLHttpRequest")
-            .header("Accept", "application/json, text/javascript, */*; q=0.01")
-            .send()
-            .await
-            .context("fetch requests HTTP request failed")?;
-
-        let status = response.status();
-        tracing::debug!(%status, "fetch requests response");
-
-        if !status.is_success() {
-            anyhow::bail!("fetch requests failed with status {status}");
-        }
-
-        let mut raw: serde_json::Value = response
-            .json()
-            .await
-            .context("failed to parse fetch requests response JSON")?;
-
-        self::strip_json_nulls(&mut raw);
-
-        let body: FetchRequestsResponse = serde_json::from_value(raw)
-            .context("failed to deserialize fetch requests response")?;
-
-        tracing::debug!(
-            total = body.i_total_display_records,
-            returned = body.aa_data.len(),
-            "fetched assistance requests"
-        );
-
-        Ok(body)
+        let url = datatables::page_url(
+            &self.list_url(),
+            &[
+                "id",
+                "id",
+                "status",
+                "dateRequested",
+                "client.lastName",
+                "client.firstName",
+                "requestAssignedToMember",
+                "streetAddressLine1",
+                "client.homePhone",
+                "client.mobilePhone",
+                "pendingItems",
+                "id",
+            ],
+            params.display_start,
+            params.display_length,
+            &[
+                ("iSortCol_0", &sort_col),
+                ("sSortDir_0", &params.sort_dir),
+                ("iSortingCols", "1"),
+                ("bSortable_0", "false"),
+                ("bSortable_1", "false"),
+                ("bSortable_2", "true"),
+                ("bSortable_3", "true"),
+                ("bSortable_4", "true"),
+                ("bSortable_5", "true"),
+                ("bSortable_6", "false"),
+                ("bSortable_7", "false"),
+                ("bSortable_8", "false"),
+                ("bSortable_9", "false"),
+                ("bSortable_10", "false"),
+                ("bSortable_11", "false"),
+                ("sSearch", &params.search),
+                ("bRegex", "false"),
+                ("filterByStatus", &params.filter_by_status),
+                ("filterByPartnerConf", &params.filter_by_partner_conf),
+                ("filterByReqAssigned", &params.filter_by_req_assigned),
+                ("filterByVisitAssigned", &params.filter_by_visit_assigned),
+            ],
+        )?;
+
+        self.fetch_data_table(url, "requests").await
     }
 }
 
@@ -368,7 +335,7 @@ impl ServWare {
 
 /// Recursively remove null-valued entries from JSON objects so that
 /// `#[serde(default)]` can provide Rust defaults for those fields.
-fn strip_json_nulls(value: &mut serde_json::Value) {
+pub(crate) fn strip_json_nulls(value: &mut serde_json::Value) {
     match value {
         serde_json::Value::Object(map) => {
             map.retain(|_, v| !v.is_null());
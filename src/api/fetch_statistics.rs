@@ -0,0 +1,58 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::error::ResultExt;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// One figure from the home page dashboard, e.g. `473` /
+/// `"Completed Requests (FY)"`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Statistic {
+    pub count: u64,
+    pub count_description: String,
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Fetch the fiscal-year statistics shown on the ServWare home page.
+    ///
+    /// The descriptions come from the server as display text, and the
+    /// monthly ones name the current month (`"Completed Requests - Feb"`).
+    pub async fn fetch_statistics(&self) -> Result<Vec<Statistic>> {
+        let url = self.statistics_url();
+        tracing::debug!(%url, "fetching statistics");
+
+        let response = self
+            .send(|client| {
+                client
+                    .get(&url)
+                    .header("X-Requested-With", "XMLHttpRequest")
+                    .header("Accept", "application/json, text/javascript, */*; q=0.01")
+            })
+            .await
+            .context("fetch statistics HTTP request failed")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ServWareError::status("fetch statistics", status));
+        }
+
+        let stats: Vec<Statistic> = response
+            .json()
+            .await
+            .context("failed to deserialize statistics response")?;
+
+        tracing::debug!(count = stats.len(), "fetched statistics");
+        Ok(stats)
+    }
+}
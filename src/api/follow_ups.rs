@@ -0,0 +1,186 @@
+use chrono::NaiveDate;
+use futures_util::Stream;
+use futures_util::TryStreamExt;
+use serde::Deserialize;
+
+use super::Result;
+use super::ServWare;
+use super::datatables;
+use super::datatables::DataTablesResponse;
+use super::error::ResultExt;
+use super::fetch_requests::Client;
+use super::scrape;
+
+/// How ServWare writes follow-up dates, e.g. `03/15/2026`.
+const FOLLOW_UP_DATE_FORMAT: &str = "%m/%d/%Y";
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// A reminder to get back to a neighbor, e.g. "call back in 2 weeks".
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct FollowUp {
+    pub id: u64,
+    /// Due date, `MM/DD/YYYY`.
+    pub follow_up_date: String,
+    pub notes: String,
+    pub completed: bool,
+    pub date_completed: Option<String>,
+    pub assigned_to_member: Option<String>,
+    /// The neighbor, in the follow-up list. Absent when the follow-up is
+    /// nested in its [`Client`].
+    pub client: Option<Client>,
+}
+
+impl FollowUp {
+    pub fn due_date(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(&self.follow_up_date, FOLLOW_UP_DATE_FORMAT).ok()
+    }
+
+    /// Still open and due on or before `on`.
+    pub fn is_due(&self, on: NaiveDate) -> bool {
+        !self.completed && self.due_date().is_some_and(|due| due <= on)
+    }
+}
+
+/// DataTables envelope of the follow-up list behind `/app/clients/followups`.
+pub type FetchFollowUpsResponse = DataTablesResponse<FollowUp>;
+
+/// Parameters for listing follow-ups.
+#[derive(Debug, Clone)]
+pub struct FetchFollowUpsParams {
+    pub display_start: u32,
+    pub display_length: u32,
+    /// `"Open"`, `"Completed"`, or empty for both.
+    pub filter_by_status: String,
+}
+
+impl FetchFollowUpsParams {
+    /// Records requested per page unless the caller picks another size.
+    pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
+    /// Config for every follow-up that hasn't been completed.
+    pub fn new_open() -> Self {
+        Self {
+            display_start: 0,
+            display_length: Self::DEFAULT_PAGE_SIZE,
+            filter_by_status: "Open".into(),
+        }
+    }
+}
+
+/// Fields for a new follow-up on a neighbor.
+#[derive(Debug, Clone)]
+pub struct NewFollowUp {
+    /// Due date, `MM/DD/YYYY`.
+    pub follow_up_date: String,
+    pub notes: String,
+    /// Member to remind; empty leaves it unassigned.
+    pub assigned_to_member_id: String,
+}
+
+impl NewFollowUp {
+    /// An unassigned follow-up due on `date`.
+    pub fn new(date: NaiveDate, notes: impl Into<String>) -> Self {
+        Self {
+            follow_up_date: date.format(FOLLOW_UP_DATE_FORMAT).to_string(),
+            notes: notes.into(),
+            assigned_to_member_id: String::new(),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Fetch one page of the follow-up list.
+    pub async fn fetch_follow_ups(
+        &self,
+        params: &FetchFollowUpsParams,
+    ) -> Result<FetchFollowUpsResponse> {
+        let url = datatables::page_url(
+            &self.follow_ups_url(),
+            &[
+                "id",
+                "followUpDate",
+                "client.lastName",
+                "client.firstName",
+                "notes",
+            ],
+            params.display_start,
+            params.display_length,
+            &[
+                ("iSortCol_0", "1"),
+                ("sSortDir_0", "asc"),
+                ("iSortingCols", "1"),
+                ("sSearch", ""),
+                ("bRegex", "false"),
+                ("filterByStatus", &params.filter_by_status),
+            ],
+        )?;
+
+        self.fetch_data_table(url, "follow-ups").await
+    }
+
+    /// Yield every follow-up matching `params`, one page at a time.
+    pub fn stream_follow_ups(
+        &self,
+        params: FetchFollowUpsParams,
+    ) -> impl Stream<Item = Result<FollowUp>> + Send + '_ {
+        let (start, length) = (params.display_start, params.display_length);
+        datatables::paginate(start, length, move |display_start| {
+            let params = FetchFollowUpsParams {
+                display_start,
+                ..params.clone()
+            };
+            async move { self.fetch_follow_ups(&params).await }
+        })
+    }
+
+    /// Every follow-up not yet completed, soonest first.
+    pub async fn open_follow_ups(&self) -> Result<Vec<FollowUp>> {
+        let mut open: Vec<FollowUp> = self
+            .stream_follow_ups(FetchFollowUpsParams::new_open())
+            .try_filter(|f| std::future::ready(!f.completed))
+            .try_collect()
+            .await?;
+        open.sort_by_key(FollowUp::due_date);
+        Ok(open)
+    }
+
+    /// Add a follow-up to a neighbor.
+    ///
+    /// Fails with [`ServWareError::Validation`](super::ServWareError) if
+    /// ServWare shows the form again with error messages instead of saving.
+    pub async fn create_follow_up(&self, client_id: u64, follow_up: &NewFollowUp) -> Result<()> {
+        let form = [
+            ("followUpDate", follow_up.follow_up_date.as_str()),
+            ("notes", follow_up.notes.as_str()),
+            (
+                "assignedToMemberId",
+                follow_up.assigned_to_member_id.as_str(),
+            ),
+        ];
+        let url = self.new_follow_up_url(client_id);
+
+        tracing::debug!(url, "posting new follow-up");
+
+        let response = self
+            .send(|client| client.post(&url).form(&form))
+            .await
+            .context("create follow-up POST failed")?;
+
+        scrape::check_saved(response, "create follow-up").await?;
+
+        tracing::info!(
+            client_id,
+            due = follow_up.follow_up_date,
+            "follow-up created"
+        );
+        Ok(())
+    }
+}
@@ -1,18 +1,91 @@
+pub mod create_client;
+pub mod create_request;
+mod datatables;
+mod error;
+pub mod fake;
+pub mod fetch_activity_report;
+pub mod fetch_approvals;
+pub mod fetch_calendar;
+pub mod fetch_client_detail;
+pub mod fetch_clients;
+pub mod fetch_item_value;
+pub mod fetch_lookups;
 pub mod fetch_members;
+pub mod fetch_request_detail;
 pub mod fetch_requests;
+pub mod fetch_statistics;
+pub mod follow_ups;
+pub mod pending_assistance;
+pub mod recording;
+pub mod request_files;
+mod scrape;
+pub mod service_hours;
+pub mod session_file;
 pub mod update_assistance;
+pub mod update_client;
 pub mod update_request;
 
+use std::future::Future;
+use std::pin::pin;
 use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
 
-use anyhow::Context;
-use anyhow::bail;
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::Utc;
+use futures_util::Stream;
+use futures_util::TryStreamExt;
+use reqwest::RequestBuilder;
+use reqwest::Response;
+use reqwest::Url;
 use reqwest::header::{self, HeaderMap, HeaderValue};
 use reqwest::redirect;
 use secrecy::ExposeSecret;
 use secrecy::SecretString;
+use tokio::task::JoinHandle;
 
-const BASE_URL: &str = "https://www.servware.org";
+pub use self::datatables::DataTablesResponse;
+pub use self::error::FieldError;
+pub use self::error::Result;
+pub use self::error::ServWareError;
+
+use self::error::ResultExt;
+use self::fetch_activity_report::ReportRow;
+use self::fetch_approvals::Approval;
+use self::fetch_calendar::CalendarEvent;
+use self::fetch_calendar::CalendarFeed;
+use self::fetch_client_detail::ClientDetail;
+use self::fetch_item_value::ItemValue;
+use self::fetch_lookups::Lookups;
+use self::fetch_members::Member;
+use self::fetch_request_detail::RequestDetail;
+use self::fetch_requests::AssistanceRequest;
+use self::fetch_requests::Client;
+use self::fetch_requests::FetchRequestsParams;
+use self::fetch_requests::FetchRequestsResponse;
+use self::fetch_statistics::Statistic;
+use self::follow_ups::FollowUp;
+use self::follow_ups::NewFollowUp;
+use self::pending_assistance::PendingAssistance;
+use self::service_hours::NewServiceHours;
+use self::session_file::CookieJar;
+use self::session_file::SessionFile;
+use self::update_assistance::UpdateAssistanceInput;
+use self::update_client::UpdateClientInput;
+use self::update_request::UpdateRequestInput;
+
+/// Production ServWare origin. Used unless another base URL is supplied.
+pub const DEFAULT_BASE_URL: &str = "https://www.servware.org";
+
+/// Env var that points the client at a different origin (e.g. a local fake).
+pub const BASE_URL_ENV: &str = "SERVWARE_BASE_URL";
+
+/// Idle time after which ServWare ends a session (api.md §1).
+pub const SESSION_TIMEOUT: Duration = Duration::from_secs(3600);
+
+/// Default period for [`ServWare::start_keep_alive`], well inside the timeout.
+pub const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(20 * 60);
 
 // ---------------------------------------------------------------------------
 // ServWare client
@@ -20,56 +93,264 @@ const BASE_URL: &str = "https://www.servware.org";
 
 /// Authenticated ServWare API client. Holds a cookie-jar-backed HTTP client
 /// so that session cookies are automatically managed.
+///
+/// The credentials are kept so that an expired session can be renewed
+/// transparently; see [`ServWare::send`]. A session resumed from a
+/// [`SessionFile`] has no password, so it can only be renewed if given a
+/// [`with_password_prompt`](ServWare::with_password_prompt) to ask for one.
 pub struct ServWare {
     pub(crate) client: reqwest::Client,
+    base_url: String,
+    cookies: Arc<CookieJar>,
+    username: String,
+    password: Mutex<Option<SecretString>>,
+    password_prompt: Option<PasswordPrompt>,
+    keep_alive: Mutex<Option<JoinHandle<()>>>,
 }
 
+/// Asks the user for their password; see [`ServWare::with_password_prompt`].
+type PasswordPrompt = Box<dyn Fn() -> anyhow::Result<SecretString> + Send + Sync>;
+
 impl ServWare {
-    fn login_url() -> String {
-        format!("{BASE_URL}/security/login")
+    /// The origin every request is sent to, without a trailing slash.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn login_url(&self) -> String {
+        format!("{}/security/login", self.base_url)
+    }
+
+    fn list_url(&self) -> String {
+        format!("{}/app/assistancerequests/list", self.base_url)
+    }
+
+    fn calendar_url(&self, feed: &str) -> String {
+        format!("{}/app/calendar/{feed}", self.base_url)
+    }
+
+    fn clients_url(&self) -> String {
+        format!("{}/app/clients/list", self.base_url)
+    }
+
+    fn new_client_url(&self) -> String {
+        format!("{}/app/clients/new", self.base_url)
+    }
+
+    fn follow_ups_url(&self) -> String {
+        format!("{}/app/clients/followups/list", self.base_url)
+    }
+
+    fn new_follow_up_url(&self, client_id: u64) -> String {
+        format!("{}/app/clients/{client_id}/followups/new", self.base_url)
+    }
+
+    fn client_url(&self, id: u64) -> String {
+        format!("{}/app/clients/{id}", self.base_url)
+    }
+
+    fn reports_url(&self) -> String {
+        format!("{}/app/reports", self.base_url)
+    }
+
+    fn service_hours_url(&self) -> String {
+        format!("{}/app/mileagehoursinservicelist/list", self.base_url)
+    }
+
+    fn new_service_hours_url(&self) -> String {
+        format!("{}/app/mileagehoursinservicelist/new", self.base_url)
+    }
+
+    fn statistics_url(&self) -> String {
+        format!("{}/app/home/statistics", self.base_url)
+    }
+
+    fn request_url(&self, id: u64) -> String {
+        format!("{}/app/assistancerequests/{id}", self.base_url)
+    }
+
+    fn new_request_url(&self) -> String {
+        format!("{}/app/assistancerequests/new", self.base_url)
+    }
+
+    fn approvals_url(&self, id: u64) -> String {
+        format!(
+            "{}/app/assistancerequests/{id}/approval/list",
+            self.base_url
+        )
+    }
+
+    fn request_files_url(&self, id: u64) -> String {
+        format!("{}/app/assistancerequests/{id}/files/list", self.base_url)
     }
 
-    fn list_url() -> String {
-        format!("{BASE_URL}/app/assistancerequests/list")
+    fn item_value_url(&self) -> String {
+        format!("{}/app/assistancerequests/itemvalue", self.base_url)
     }
 
-    fn request_url(id: u64) -> String {
-        format!("{BASE_URL}/app/assistancerequests/{id}")
+    fn assistance_item_url(&self, id: u64) -> String {
+        format!(
+            "{}/app/assistancerequests/{id}/assistanceitems/new",
+            self.base_url
+        )
     }
 
-    fn assistance_item_url(id: u64) -> String {
-        format!("{BASE_URL}/app/assistancerequests/{id}/assistanceitems/new")
+    fn edit_assistance_item_url(&self, request_id: u64, item_id: u64) -> String {
+        format!(
+            "{}/app/assistancerequests/{request_id}/assistanceitems/{item_id}",
+            self.base_url
+        )
     }
 
-    fn extend_session_url() -> String {
-        format!("{BASE_URL}/security/extendSession")
+    fn pending_assistance_url(&self) -> String {
+        format!("{}/app/assistancerequests/pendingassistance", self.base_url)
     }
+
+    fn logout_url(&self) -> String {
+        format!("{}/security/logout", self.base_url)
+    }
+
+    fn extend_session_url(&self) -> String {
+        format!("{}/security/extendSession", self.base_url)
+    }
+}
+
+/// ServWare answers any call made without a live session by redirecting to
+/// `/security/redirectLogin`, which in turn lands on `/security/login`.
+fn is_login_page(url: &Url) -> bool {
+    matches!(url.path(), "/security/login" | "/security/redirectLogin")
+}
+
+/// Trim and validate a base URL so the URL helpers can append paths to it.
+fn normalize_base_url(base_url: &str) -> Result<String> {
+    let base_url = base_url.trim().trim_end_matches('/').to_string();
+    if let Err(e) = Url::parse(&base_url) {
+        return Err(ServWareError::InvalidInput(format!(
+            "invalid base URL {base_url}: {e}"
+        )));
+    }
+    Ok(base_url)
+}
+
+/// HTTP client with browser-like headers that keeps its cookies in `jar`.
+fn build_client(jar: Arc<CookieJar>) -> Result<reqwest::Client> {
+    let mut headers = HeaderMap::new();
+    headers.insert(header::ACCEPT, HeaderValue::from_static(
+        "text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,image/apng,*/*;q=0.8",
+    ));
+    headers.insert(header::ACCEPT_LANGUAGE, HeaderValue::from_static("en-US,en;q=0.8"));
+    headers.insert("sec-fetch-dest", HeaderValue::from_static("document"));
+    headers.insert("sec-fetch-mode", HeaderValue::from_static("navigate"));
+    headers.insert("sec-fetch-site", HeaderValue::from_static("same-origin"));
+    headers.insert("sec-fetch-user", HeaderValue::from_static("?1"));
+    headers.insert(header::UPGRADE_INSECURE_REQUESTS, HeaderValue::from_static("1"));
+
+    reqwest::Client::builder()
+        .cookie_provider(jar)
+        .redirect(redirect::Policy::limited(10))
+        .user_agent("Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/145.0.0.0 Safari/537.36")
+        .default_headers(headers)
+        .build()
+        .context("failed to build HTTP client")
 }
 
 impl ServWare {
     /// Authenticate with ServWare and return a new session.
-    pub async fn new_session(username: &str, password: &SecretString) -> anyhow::Result<Self> {
-        let jar = Arc::new(reqwest::cookie::Jar::default());
-        let mut headers = HeaderMap::new();
-        headers.insert(header::ACCEPT, HeaderValue::from_static(
-            "text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,image/apng,*/*;q=0.8",
-        ));
-        headers.insert(header::ACCEPT_LANGUAGE, HeaderValue::from_static("en-US,en;q=0.8"));
-        headers.insert("sec-fetch-dest", HeaderValue::from_static("document"));
-        headers.insert("sec-fetch-mode", HeaderValue::from_static("navigate"));
-        headers.insert("sec-fetch-site", HeaderValue::from_static("same-origin"));
-        headers.insert("sec-fetch-user", HeaderValue::from_static("?1"));
-        headers.insert(header::UPGRADE_INSECURE_REQUESTS, HeaderValue::from_static("1"));
-
-        let client = reqwest::Client::builder()
-            .cookie_provider(jar)
-            .redirect(redirect::Policy::limited(10))
-            .user_agent("Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/145.0.0.0 Safari/537.36")
-            .default_headers(headers)
-            .build()
-            .context("failed to build HTTP client")?;
-
-        let url = Self::login_url();
+    ///
+    /// `base_url` is the origin to talk to, normally [`DEFAULT_BASE_URL`].
+    /// Pointing it elsewhere (e.g. `http://localhost:8080`) runs every call
+    /// against that server instead of production.
+    pub async fn new_session(
+        base_url: &str,
+        username: &str,
+        password: &SecretString,
+    ) -> Result<Self> {
+        let base_url = self::normalize_base_url(base_url)?;
+        let cookies = Arc::new(CookieJar::default());
+        let this = Self {
+            client: self::build_client(cookies.clone())?,
+            base_url,
+            cookies,
+            username: username.to_string(),
+            password: Mutex::new(Some(password.clone())),
+            password_prompt: None,
+            keep_alive: Mutex::new(None),
+        };
+        this.login().await?;
+        Ok(this)
+    }
+
+    /// Pick up a session saved by [`save_session`](Self::save_session) on an
+    /// earlier run.
+    ///
+    /// Returns `None` when there is nothing usable to resume: no file, a
+    /// file for another server, one that can't be read or decrypted, or a
+    /// session ServWare no longer accepts. Callers then log in as usual.
+    pub async fn resume_session(base_url: &str, file: &SessionFile) -> Result<Option<Self>> {
+        let base_url = self::normalize_base_url(base_url)?;
+        let path = file.path().display();
+
+        let saved = match file.load() {
+            Ok(Some(saved)) => saved,
+            Ok(None) => return Ok(None),
+            Err(e) => {
+                tracing::warn!("ignoring session file {path}: {e:#}");
+                return Ok(None);
+            }
+        };
+        if saved.base_url != base_url {
+            tracing::info!(
+                "session file {path} is for {}, not {base_url}",
+                saved.base_url
+            );
+            return Ok(None);
+        }
+
+        let cookies = Arc::new(saved.cookies);
+        let this = Self {
+            client: self::build_client(cookies.clone())?,
+            base_url,
+            cookies,
+            username: saved.username,
+            password: Mutex::new(None),
+            password_prompt: None,
+            keep_alive: Mutex::new(None),
+        };
+
+        if let Err(e) = this.ping().await {
+            tracing::info!("saved session is stale: {e:#}");
+            return Ok(None);
+        }
+
+        tracing::info!(username = %this.username, "resumed saved session");
+        Ok(Some(this))
+    }
+
+    /// Ask `prompt` for the password the first time this session has to log
+    /// in again without one, i.e. when a session resumed from a file expires
+    /// partway through a command. Without a prompt that fails with
+    /// [`ServWareError::NoCredentials`].
+    pub fn with_password_prompt(
+        mut self,
+        prompt: impl Fn() -> anyhow::Result<SecretString> + Send + Sync + 'static,
+    ) -> Self {
+        self.password_prompt = Some(Box::new(prompt));
+        self
+    }
+
+    /// Write this session's cookies to `file` so a later run can
+    /// [`resume_session`](Self::resume_session) without logging in.
+    pub fn save_session(&self, file: &SessionFile) -> Result<()> {
+        Ok(file.save(&self.base_url, &self.username, &self.cookies)?)
+    }
+
+    /// Log in with the stored credentials. The cookie jar picks up the new
+    /// session cookie, so every later call on this client uses it.
+    async fn login(&self) -> Result<()> {
+        let password = self.password()?;
+
+        let url = self.login_url();
+        let username = self.username.as_str();
         tracing::debug!(%url, %username, "attempting login");
 
         let params = [
@@ -77,10 +358,11 @@ impl ServWare {
             ("password", password.expose_secret()),
         ];
 
-        let response = client
+        let response = self
+            .client
             .post(&url)
-            .header(header::ORIGIN, "https://www.servware.org")
-            .header(header::REFERER, "https://www.servware.org/security/login")
+            .header(header::ORIGIN, &self.base_url)
+            .header(header::REFERER, &url)
             .form(&params)
             .send()
             .await
@@ -93,26 +375,80 @@ impl ServWare {
         // A failed login redirects back to the login page.
         if final_url.contains("/security/login") {
             tracing::error!("{response:?}");
-            bail!("login failed: redirected back to login page (bad credentials?)");
+            return Err(ServWareError::BadCredentials);
         }
 
         if !status.is_success() {
-            bail!("login failed with status {status}");
+            return Err(ServWareError::status("login", status));
         }
 
+        // Keep a prompted password for the next renewal, now that it's
+        // known to work.
+        self.password
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get_or_insert(password);
+
         tracing::info!("logged in successfully");
-        Ok(Self { client })
+        Ok(())
+    }
+
+    /// The stored password, or one from the password prompt if there is
+    /// none yet.
+    fn password(&self) -> Result<SecretString> {
+        let stored = self
+            .password
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        if let Some(password) = stored {
+            return Ok(password);
+        }
+
+        let Some(prompt) = &self.password_prompt else {
+            return Err(ServWareError::NoCredentials);
+        };
+        tracing::warn!(
+            username = %self.username,
+            "saved session expired; asking for the password to log in again"
+        );
+        Ok(prompt()?)
+    }
+
+    /// Send a request, renewing the session and retrying once if ServWare
+    /// bounced it to the login page.
+    ///
+    /// `build` is called once per attempt so the request can be rebuilt for
+    /// the retry. A bounced POST was never applied, so resending it is safe.
+    pub(crate) async fn send(
+        &self,
+        build: impl Fn(&reqwest::Client) -> RequestBuilder,
+    ) -> Result<Response> {
+        let response = build(&self.client).send().await?;
+        if !self::is_login_page(response.url()) {
+            return Ok(response);
+        }
+
+        tracing::warn!("session expired; logging in again");
+        self.login().await.map_err(|e| match e {
+            ServWareError::NoCredentials => e,
+            e => ServWareError::ReloginFailed(Box::new(e)),
+        })?;
+
+        let response = build(&self.client).send().await?;
+        if self::is_login_page(response.url()) {
+            return Err(ServWareError::SessionExpired);
+        }
+        Ok(response)
     }
 
     /// Extend the current ServWare session to keep it alive.
-    pub async fn extend_session(&self) -> anyhow::Result<()> {
-        let url = Self::extend_session_url();
+    pub async fn extend_session(&self) -> Result<()> {
+        let url = self.extend_session_url();
         tracing::debug!(%url, "extending session");
 
         let response = self
-            .client
-            .get(&url)
-            .send()
+            .send(|client| client.get(&url))
             .await
             .context("extend session request failed")?;
 
@@ -120,14 +456,406 @@ impl ServWare {
         tracing::debug!(%status, "extend session response");
 
         if !status.is_success() {
-            bail!("session extend failed with status {status} — session may have expired");
+            return Err(ServWareError::status("session extend", status));
         }
 
         Ok(())
     }
 
-    /// Ping ServWare by extending the session. Confirms the session is still active.
-    pub async fn ping(&self) -> anyhow::Result<()> {
-        self.extend_session().await
+    /// End the session on the server and stop any keep-alive. The client
+    /// should not be used afterwards.
+    pub async fn logout(&self) -> Result<()> {
+        self.stop_keep_alive();
+
+        let url = self.logout_url();
+        tracing::debug!(%url, "logging out");
+
+        let response = self
+            .client
+            .post(&url)
+            .header(header::ORIGIN, &self.base_url)
+            .send()
+            .await
+            .context("logout request failed")?;
+
+        let status = response.status();
+        tracing::debug!(%status, "logout response");
+
+        if !status.is_success() && !status.is_redirection() {
+            return Err(ServWareError::status("logout", status));
+        }
+
+        tracing::info!("logged out");
+        Ok(())
+    }
+
+    /// Start a background task that extends the session every `every` so it
+    /// outlives ServWare's idle timeout. Replaces any keep-alive already
+    /// running. The task stops on [`stop_keep_alive`](Self::stop_keep_alive)
+    /// or when the client is dropped.
+    pub fn start_keep_alive(&self, every: Duration) -> Result<()> {
+        if every.is_zero() || every >= SESSION_TIMEOUT {
+            return Err(ServWareError::InvalidInput(format!(
+                "keep-alive interval must be between zero and the {}s session timeout",
+                SESSION_TIMEOUT.as_secs()
+            )));
+        }
+
+        let client = self.client.clone();
+        let url = self.extend_session_url();
+        let task = tokio::spawn(async move {
+            let start = tokio::time::Instant::now() + every;
+            let mut ticks = tokio::time::interval_at(start, every);
+            loop {
+                ticks.tick().await;
+                match client.get(&url).send().await {
+                    Ok(response) if self::is_login_page(response.url()) => {
+                        tracing::warn!("keep-alive found the session expired");
+                    }
+                    Ok(response) => {
+                        tracing::debug!(status = %response.status(), "keep-alive extended session");
+                    }
+                    Err(e) => tracing::warn!("keep-alive request failed: {e}"),
+                }
+            }
+        });
+
+        let previous = self
+            .keep_alive
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .replace(task);
+        if let Some(previous) = previous {
+            previous.abort();
+        }
+
+        tracing::info!(every_secs = every.as_secs(), "started session keep-alive");
+        Ok(())
+    }
+
+    /// Stop the keep-alive task, if one is running.
+    pub fn stop_keep_alive(&self) {
+        let task = self
+            .keep_alive
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        if let Some(task) = task {
+            task.abort();
+            tracing::debug!("stopped session keep-alive");
+        }
+    }
+
+    /// Ping ServWare by extending the session. Confirms the session is still
+    /// active; unlike other calls, an expired session is reported rather than
+    /// renewed.
+    pub async fn ping(&self) -> Result<()> {
+        let url = self.extend_session_url();
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("ping request failed")?;
+
+        let status = response.status();
+        if self::is_login_page(response.url()) {
+            return Err(ServWareError::SessionExpired);
+        }
+        if !status.is_success() {
+            return Err(ServWareError::status("ping", status));
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for ServWare {
+    fn drop(&mut self) {
+        self.stop_keep_alive();
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Backend trait
+// ---------------------------------------------------------------------------
+
+/// The ServWare operations the nativity workflows are built on.
+///
+/// [`ServWare`] talks to the real server. [`fake::FakeServWare`] keeps
+/// everything in memory, and [`recording::Recording`] wraps any backend to
+/// log the calls made through it.
+pub trait ServWareApi: Sync {
+    /// One page of assistance requests matching `params`.
+    fn fetch_requests(
+        &self,
+        params: &FetchRequestsParams,
+    ) -> impl Future<Output = Result<FetchRequestsResponse>> + Send;
+
+    /// Every request matching `params`, fetched page by page so nothing is
+    /// cut off at the page size. `params.display_length` sets the page size.
+    fn stream_requests(
+        &self,
+        params: FetchRequestsParams,
+    ) -> impl Stream<Item = Result<AssistanceRequest>> + Send + '_
+    where
+        Self: Sized,
+    {
+        let (start, length) = (params.display_start, params.display_length);
+        datatables::paginate(start, length, move |display_start| {
+            let params = FetchRequestsParams {
+                display_start,
+                ..params.clone()
+            };
+            async move { self.fetch_requests(&params).await }
+        })
+    }
+
+    /// A single request by ID, whatever its status (open, completed, denied).
+    fn fetch_request(&self, id: u64) -> impl Future<Output = Result<AssistanceRequest>> + Send
+    where
+        Self: Sized,
+    {
+        async move {
+            let mut matches = pin!(self.stream_requests(FetchRequestsParams::new_by_id(id)));
+            while let Some(request) = matches.try_next().await? {
+                if request.id == id {
+                    return Ok(request);
+                }
+            }
+            Err(ServWareError::NotFound(format!("request {id}")))
+        }
+    }
+
+    /// Volunteer members that requests can be assigned to.
+    fn fetch_members(&self, request_id: u64) -> impl Future<Output = Result<Vec<Member>>> + Send;
+
+    /// Merge `input` over the request's current state and save it.
+    fn update_request(
+        &self,
+        request_id: u64,
+        input: &UpdateRequestInput,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// Add one assistance item to a request.
+    fn update_assistance(
+        &self,
+        request_id: u64,
+        input: &UpdateAssistanceInput,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// Merge `input` over the neighbor's current record and save it.
+    fn update_client(
+        &self,
+        client_id: u64,
+        input: &UpdateClientInput,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// A neighbor's full record.
+    fn fetch_client_detail(
+        &self,
+        client_id: u64,
+    ) -> impl Future<Output = Result<ClientDetail>> + Send;
+
+    /// Add a new neighbor and return their client ID.
+    fn create_client(&self, client: &ClientDetail) -> impl Future<Output = Result<u64>> + Send;
+
+    /// Open a new request for a neighbor and return its ID.
+    fn create_request(
+        &self,
+        client_id: u64,
+        request: &RequestDetail,
+    ) -> impl Future<Output = Result<u64>> + Send;
+
+    /// Log one mileage and hours entry.
+    fn create_service_hours(
+        &self,
+        entry: &NewServiceHours,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// Every assistance item promised but not yet paid.
+    fn fetch_pending_assistance(
+        &self,
+    ) -> impl Future<Output = Result<Vec<PendingAssistance>>> + Send;
+
+    /// Record a pending assistance item as paid by check.
+    fn mark_assistance_paid(
+        &self,
+        request_id: u64,
+        item_id: u64,
+        date_paid: &str,
+        check_number: &str,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// The conference activity report for `from` through `to`, inclusive.
+    fn fetch_activity_report(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> impl Future<Output = Result<Vec<ReportRow>>> + Send;
+
+    /// The fiscal-year figures from the home page dashboard.
+    fn fetch_statistics(&self) -> impl Future<Output = Result<Vec<Statistic>>> + Send;
+
+    /// The default value configured for an assistance type and client.
+    fn fetch_item_value(
+        &self,
+        assistance_type_id: &str,
+        client_id: u64,
+    ) -> impl Future<Output = Result<ItemValue>> + Send;
+
+    /// A request's approval chain, empty if it needs no approval.
+    fn fetch_approvals(
+        &self,
+        request_id: u64,
+    ) -> impl Future<Output = Result<Vec<Approval>>> + Send;
+
+    /// The origin the paths in ServWare's links are relative to.
+    fn base_url(&self) -> &str;
+
+    /// The conference's dropdown lookup tables, read from one request's
+    /// forms.
+    fn fetch_lookups(&self, request_id: u64) -> impl Future<Output = Result<Lookups>> + Send;
+
+    /// Neighbors whose name, phone number or address matches `query`.
+    fn search_clients(&self, query: &str) -> impl Future<Output = Result<Vec<Client>>> + Send;
+
+    /// Every follow-up not yet completed, soonest first.
+    fn open_follow_ups(&self) -> impl Future<Output = Result<Vec<FollowUp>>> + Send;
+
+    /// Open follow-ups due on or before `on`, overdue ones included.
+    fn due_follow_ups(&self, on: NaiveDate) -> impl Future<Output = Result<Vec<FollowUp>>> + Send
+    where
+        Self: Sized,
+    {
+        async move {
+            let mut open = self.open_follow_ups().await?;
+            open.retain(|f| f.is_due(on));
+            Ok(open)
+        }
+    }
+
+    /// Add a follow-up to a neighbor.
+    fn create_follow_up(
+        &self,
+        client_id: u64,
+        follow_up: &NewFollowUp,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// Events on one of the calendars between `start` and `end`.
+    fn fetch_calendar(
+        &self,
+        feed: CalendarFeed,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<CalendarEvent>>> + Send;
+}
+
+impl ServWareApi for ServWare {
+    async fn fetch_requests(&self, params: &FetchRequestsParams) -> Result<FetchRequestsResponse> {
+        ServWare::fetch_requests(self, params).await
+    }
+
+    async fn fetch_members(&self, request_id: u64) -> Result<Vec<Member>> {
+        ServWare::fetch_members(self, request_id).await
+    }
+
+    async fn update_request(&self, request_id: u64, input: &UpdateRequestInput) -> Result<()> {
+        ServWare::update_request(self, request_id, input).await
+    }
+
+    async fn update_assistance(
+        &self,
+        request_id: u64,
+        input: &UpdateAssistanceInput,
+    ) -> Result<()> {
+        ServWare::update_assistance(self, request_id, input).await
+    }
+
+    async fn update_client(&self, client_id: u64, input: &UpdateClientInput) -> Result<()> {
+        ServWare::update_client(self, client_id, input).await
+    }
+
+    async fn fetch_client_detail(&self, client_id: u64) -> Result<ClientDetail> {
+        ServWare::fetch_client_detail(self, client_id).await
+    }
+
+    async fn create_client(&self, client: &ClientDetail) -> Result<u64> {
+        ServWare::create_client(self, client).await
+    }
+
+    async fn create_request(&self, client_id: u64, request: &RequestDetail) -> Result<u64> {
+        ServWare::create_request(self, client_id, request).await
+    }
+
+    async fn create_service_hours(&self, entry: &NewServiceHours) -> Result<()> {
+        ServWare::create_service_hours(self, entry).await
+    }
+
+    async fn fetch_pending_assistance(&self) -> Result<Vec<PendingAssistance>> {
+        ServWare::fetch_pending_assistance(self).await
+    }
+
+    async fn mark_assistance_paid(
+        &self,
+        request_id: u64,
+        item_id: u64,
+        date_paid: &str,
+        check_number: &str,
+    ) -> Result<()> {
+        ServWare::mark_assistance_paid(self, request_id, item_id, date_paid, check_number).await
+    }
+
+    async fn fetch_activity_report(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<ReportRow>> {
+        ServWare::fetch_activity_report(self, from, to).await
+    }
+
+    async fn fetch_statistics(&self) -> Result<Vec<Statistic>> {
+        ServWare::fetch_statistics(self).await
+    }
+
+    async fn fetch_item_value(
+        &self,
+        assistance_type_id: &str,
+        client_id: u64,
+    ) -> Result<ItemValue> {
+        ServWare::fetch_item_value(self, assistance_type_id, client_id).await
+    }
+
+    async fn fetch_approvals(&self, request_id: u64) -> Result<Vec<Approval>> {
+        ServWare::fetch_approvals(self, request_id).await
+    }
+
+    fn base_url(&self) -> &str {
+        ServWare::base_url(self)
+    }
+
+    async fn fetch_lookups(&self, request_id: u64) -> Result<Lookups> {
+        ServWare::fetch_lookups(self, request_id).await
+    }
+
+    async fn search_clients(&self, query: &str) -> Result<Vec<Client>> {
+        ServWare::search_clients(self, query).await
+    }
+
+    async fn open_follow_ups(&self) -> Result<Vec<FollowUp>> {
+        ServWare::open_follow_ups(self).await
+    }
+
+    async fn create_follow_up(&self, client_id: u64, follow_up: &NewFollowUp) -> Result<()> {
+        ServWare::create_follow_up(self, client_id, follow_up).await
+    }
+
+    async fn fetch_calendar(
+        &self,
+        feed: CalendarFeed,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<CalendarEvent>> {
+        ServWare::fetch_calendar(self, feed, start, end).await
     }
 }
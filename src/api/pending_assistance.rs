@@ -0,0 +1,278 @@
+use scraper::Html;
+use scraper::Selector;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::error::ResultExt;
+use super::scrape;
+use super::update_request::push_checkbox;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// An assistance item promised to a neighbor but not yet paid, as listed on
+/// the pending assistance page.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PendingAssistance {
+    pub request_id: u64,
+    pub item_id: u64,
+    pub neighbor: String,
+    pub assistance_type: String,
+    /// `None` if the amount column is blank or not a number.
+    pub amount: Option<f64>,
+    pub payee_name: String,
+    pub promised_date: String,
+    pub date_provided: String,
+}
+
+/// Which [`PendingAssistance`] field a column of the pending table holds.
+#[derive(Clone, Copy, PartialEq)]
+enum Column {
+    Neighbor,
+    AssistanceType,
+    Amount,
+    PayeeName,
+    PromisedDate,
+    DateProvided,
+    Other,
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Every pending assistance item across all requests, e.g. checks the
+    /// treasurer still has to write.
+    pub async fn fetch_pending_assistance(&self) -> Result<Vec<PendingAssistance>> {
+        let html = self
+            .fetch_page(&self.pending_assistance_url(), "pending assistance page")
+            .await?;
+
+        let pending = self::parse_pending_assistance(&html)?;
+        tracing::debug!(count = pending.len(), "parsed pending assistance");
+        Ok(pending)
+    }
+
+    /// Settle a pending assistance item using read-modify-write: the item's
+    /// edit form is posted back with `pending` cleared and the payment
+    /// recorded.
+    ///
+    /// `date_paid` is `MM/DD/YYYY`, like every date ServWare takes.
+    pub async fn mark_assistance_paid(
+        &self,
+        request_id: u64,
+        item_id: u64,
+        date_paid: &str,
+        check_number: &str,
+    ) -> Result<()> {
+        let url = self.edit_assistance_item_url(request_id, item_id);
+        let html = self.fetch_page(&url, "assistance item form").await?;
+
+        let item_form = format!(
+            r#"form[action$="/app/assistancerequests/{request_id}/assistanceitems/{item_id}"]"#
+        );
+        let mut form = scrape::form_values(&Html::parse_document(&html), &item_form)
+            .filter(|fields| scrape::form_value(fields, "monetaryValue").is_some())
+            .ok_or_else(|| {
+                ServWareError::PageChanged(format!(
+                    "no assistance item form found for item {item_id} of request {request_id}"
+                ))
+            })?;
+        form.retain(|(name, _)| {
+            !["pending", "_pending", "datePaid", "checkNumber", "action"].contains(&name.as_str())
+        });
+        push_checkbox(&mut form, "pending", false);
+        form.push(("datePaid".into(), date_paid.into()));
+        form.push(("checkNumber".into(), check_number.into()));
+        form.push(("action".into(), "save".into()));
+
+        tracing::debug!(url, fields = form.len(), "posting assistance payment");
+
+        let response = self
+            .send(|client| client.post(&url).form(&form))
+            .await
+            .context("mark assistance paid POST failed")?;
+
+        scrape::check_saved(response, "mark assistance paid").await?;
+
+        tracing::info!(request_id, item_id, check_number, "assistance marked paid");
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Read the pending assistance table, matching columns by their header
+/// text. Request and item IDs come from each row's link to the item, so
+/// rows without one are skipped.
+///
+/// Fails with [`ServWareError::PageChanged`] if there's no header row or it
+/// lacks a neighbor or amount column; a table with no rows is just empty.
+fn parse_pending_assistance(html: &str) -> Result<Vec<PendingAssistance>> {
+    let document = Html::parse_document(html);
+    let rows = Selector::parse("tr").expect("valid CSS selector");
+    let headers = Selector::parse("th").expect("valid CSS selector");
+    let cells = Selector::parse("td").expect("valid CSS selector");
+    let links = Selector::parse("a[href]").expect("valid CSS selector");
+
+    let mut columns = Vec::new();
+    let mut pending = Vec::new();
+    for row in document.select(&rows) {
+        let header: Vec<String> = row.select(&headers).map(scrape::cell_text).collect();
+        if !header.is_empty() {
+            columns = header.iter().map(|h| self::column(h)).collect();
+            continue;
+        }
+
+        let Some((request_id, item_id)) = row
+            .select(&links)
+            .filter_map(|a| a.value().attr("href"))
+            .find_map(self::item_ids)
+        else {
+            continue;
+        };
+
+        let mut item = PendingAssistance {
+            request_id,
+            item_id,
+            ..Default::default()
+        };
+        let values = row.select(&cells).map(scrape::cell_text);
+        for (column, value) in columns.iter().zip(values) {
+            match column {
+                Column::Neighbor => item.neighbor = value,
+                Column::AssistanceType => item.assistance_type = value,
+                Column::Amount => item.amount = scrape::parse_amount(&value),
+                Column::PayeeName => item.payee_name = value,
+                Column::PromisedDate => item.promised_date = value,
+                Column::DateProvided => item.date_provided = value,
+                Column::Other => {}
+            }
+        }
+        pending.push(item);
+    }
+
+    if columns.is_empty() {
+        return Err(ServWareError::PageChanged(
+            "no pending assistance table found".into(),
+        ));
+    }
+    for (name, wanted) in [("neighbor", Column::Neighbor), ("amount", Column::Amount)] {
+        if !columns.contains(&wanted) {
+            return Err(ServWareError::PageChanged(format!(
+                "pending assistance table has no {name} column"
+            )));
+        }
+    }
+    Ok(pending)
+}
+
+fn column(header: &str) -> Column {
+    let header = header.to_lowercase();
+    // Amount first: "Assistance Amount" is the amount, not the type.
+    if header.contains("amount") || header.contains("value") {
+        Column::Amount
+    } else if header.contains("payee") {
+        Column::PayeeName
+    } else if header.contains("promised") {
+        Column::PromisedDate
+    } else if header.contains("date") {
+        Column::DateProvided
+    } else if header.contains("type") || header.contains("assistance") {
+        Column::AssistanceType
+    } else if ["neighbor", "client", "name"]
+        .iter()
+        .any(|word| header.contains(word))
+    {
+        Column::Neighbor
+    } else {
+        Column::Other
+    }
+}
+
+/// `(request_id, item_id)` from a link such as
+/// `/app/assistancerequests/12/assistanceitems/34`.
+fn item_ids(href: &str) -> Option<(u64, u64)> {
+    let (_, rest) = href.split_once("/assistancerequests/")?;
+    let (request_id, rest) = rest.split_once("/assistanceitems/")?;
+    let item_id = rest.split(['/', '?', '#']).next()?;
+    Some((request_id.parse().ok()?, item_id.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ServWareError;
+    use super::parse_pending_assistance;
+
+    const HEADER: &str = "<tr><th>Request</th><th>Neighbor</th><th>Assistance Type</th>\
+        <th>Amount</th><th>Payee Name</th><th>Promised Date</th><th>Date Provided</th></tr>";
+
+    fn page(header: &str, rows: &str) -> String {
+        format!(
+            "<html><body><table><thead>{header}</thead><tbody>{rows}</tbody></table></body></html>"
+        )
+    }
+
+    #[test]
+    fn reads_rows_by_header_text() {
+        let html = page(
+            HEADER,
+            r#"<tr><td><a href="/app/assistancerequests/101/assistanceitems/7">101</a></td>
+                <td>Lopez, Ana</td><td>Rent</td><td>$1,250.00</td><td>Acme Apartments</td>
+                <td>03/09/2026</td><td>03/02/2026</td></tr>
+               <tr><td>no link</td><td>Skipped</td><td></td><td></td><td></td><td></td><td></td></tr>"#,
+        );
+
+        let pending = parse_pending_assistance(&html).unwrap();
+        assert_eq!(pending.len(), 1);
+        let item = &pending[0];
+        assert_eq!((item.request_id, item.item_id), (101, 7));
+        assert_eq!(item.neighbor, "Lopez, Ana");
+        assert_eq!(item.assistance_type, "Rent");
+        assert_eq!(item.amount, Some(1250.0));
+        assert_eq!(item.payee_name, "Acme Apartments");
+        assert_eq!(item.promised_date, "03/09/2026");
+        assert_eq!(item.date_provided, "03/02/2026");
+    }
+
+    #[test]
+    fn assistance_amount_header_is_the_amount() {
+        let header = "<tr><th>Request</th><th>Neighbor</th><th>Assistance</th>\
+            <th>Assistance Amount</th></tr>";
+        let html = page(
+            header,
+            r#"<tr><td><a href="/app/assistancerequests/101/assistanceitems/7">101</a></td>
+                <td>Lopez, Ana</td><td>Rent</td><td>$1,250.00</td></tr>"#,
+        );
+
+        let pending = parse_pending_assistance(&html).unwrap();
+        assert_eq!(pending[0].assistance_type, "Rent");
+        assert_eq!(pending[0].amount, Some(1250.0));
+    }
+
+    #[test]
+    fn empty_table_is_no_items() {
+        let pending = parse_pending_assistance(&page(HEADER, "")).unwrap();
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn missing_table_is_page_changed() {
+        let err = parse_pending_assistance("<html><body><p>No access</p></body></html>");
+        assert!(matches!(err, Err(ServWareError::PageChanged(_))));
+    }
+
+    #[test]
+    fn missing_amount_column_is_page_changed() {
+        let header = "<tr><th>Request</th><th>Neighbor</th><th>Payee Name</th></tr>";
+        let err = parse_pending_assistance(&page(header, "")).unwrap_err();
+        assert!(err.to_string().contains("amount"), "{err}");
+    }
+}
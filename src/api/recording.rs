@@ -0,0 +1,293 @@
+use std::sync::Mutex;
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::Utc;
+
+use super::Result;
+use super::ServWareApi;
+use super::fetch_activity_report::ReportRow;
+use super::fetch_approvals::Approval;
+use super::fetch_calendar::CalendarEvent;
+use super::fetch_calendar::CalendarFeed;
+use super::fetch_client_detail::ClientDetail;
+use super::fetch_item_value::ItemValue;
+use super::fetch_lookups::Lookups;
+use super::fetch_members::Member;
+use super::fetch_request_detail::RequestDetail;
+use super::fetch_requests::Client;
+use super::fetch_requests::FetchRequestsParams;
+use super::fetch_requests::FetchRequestsResponse;
+use super::fetch_statistics::Statistic;
+use super::follow_ups::FollowUp;
+use super::follow_ups::NewFollowUp;
+use super::pending_assistance::PendingAssistance;
+use super::service_hours::NewServiceHours;
+use super::update_assistance::UpdateAssistanceInput;
+use super::update_client::UpdateClientInput;
+use super::update_request::UpdateRequestInput;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// One call made through a [`Recording`] backend.
+#[derive(Debug, Clone)]
+pub enum Call {
+    FetchRequests(FetchRequestsParams),
+    FetchMembers {
+        request_id: u64,
+    },
+    UpdateRequest {
+        request_id: u64,
+        input: Box<UpdateRequestInput>,
+    },
+    UpdateAssistance {
+        request_id: u64,
+        input: UpdateAssistanceInput,
+    },
+    UpdateClient {
+        client_id: u64,
+        input: Box<UpdateClientInput>,
+    },
+    FetchClientDetail {
+        client_id: u64,
+    },
+    CreateClient(Box<ClientDetail>),
+    CreateRequest {
+        client_id: u64,
+        request: Box<RequestDetail>,
+    },
+    CreateServiceHours(NewServiceHours),
+    FetchPendingAssistance,
+    MarkAssistancePaid {
+        request_id: u64,
+        item_id: u64,
+        date_paid: String,
+        check_number: String,
+    },
+    FetchActivityReport {
+        from: NaiveDate,
+        to: NaiveDate,
+    },
+    FetchStatistics,
+    FetchItemValue {
+        assistance_type_id: String,
+        client_id: u64,
+    },
+    FetchApprovals {
+        request_id: u64,
+    },
+    FetchLookups {
+        request_id: u64,
+    },
+    SearchClients(String),
+    FetchOpenFollowUps,
+    CreateFollowUp {
+        client_id: u64,
+        follow_up: NewFollowUp,
+    },
+    FetchCalendar {
+        feed: CalendarFeed,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    },
+}
+
+/// Wraps any [`ServWareApi`] backend and records every call, in order,
+/// before forwarding it. Calls are recorded whether or not they succeed;
+/// [`ServWareApi::base_url`] is not a call and is only forwarded.
+#[derive(Debug, Default)]
+pub struct Recording<A> {
+    inner: A,
+    calls: Mutex<Vec<Call>>,
+}
+
+impl<A> Recording<A> {
+    pub fn new(inner: A) -> Self {
+        Self {
+            inner,
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The wrapped backend.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Every call recorded so far.
+    pub fn calls(&self) -> Vec<Call> {
+        self.calls
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    fn record(&self, call: Call) {
+        self.calls
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(call);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl<A: ServWareApi> ServWareApi for Recording<A> {
+    async fn fetch_requests(&self, params: &FetchRequestsParams) -> Result<FetchRequestsResponse> {
+        self.record(Call::FetchRequests(params.clone()));
+        self.inner.fetch_requests(params).await
+    }
+
+    async fn fetch_members(&self, request_id: u64) -> Result<Vec<Member>> {
+        self.record(Call::FetchMembers { request_id });
+        self.inner.fetch_members(request_id).await
+    }
+
+    async fn update_request(&self, request_id: u64, input: &UpdateRequestInput) -> Result<()> {
+        self.record(Call::UpdateRequest {
+            request_id,
+            input: Box::new(input.clone()),
+        });
+        self.inner.update_request(request_id, input).await
+    }
+
+    async fn update_assistance(
+        &self,
+        request_id: u64,
+        input: &UpdateAssistanceInput,
+    ) -> Result<()> {
+        self.record(Call::UpdateAssistance {
+            request_id,
+            input: input.clone(),
+        });
+        self.inner.update_assistance(request_id, input).await
+    }
+
+    async fn update_client(&self, client_id: u64, input: &UpdateClientInput) -> Result<()> {
+        self.record(Call::UpdateClient {
+            client_id,
+            input: Box::new(input.clone()),
+        });
+        self.inner.update_client(client_id, input).await
+    }
+
+    async fn fetch_client_detail(&self, client_id: u64) -> Result<ClientDetail> {
+        self.record(Call::FetchClientDetail { client_id });
+        self.inner.fetch_client_detail(client_id).await
+    }
+
+    async fn create_client(&self, client: &ClientDetail) -> Result<u64> {
+        self.record(Call::CreateClient(Box::new(client.clone())));
+        self.inner.create_client(client).await
+    }
+
+    async fn create_request(&self, client_id: u64, request: &RequestDetail) -> Result<u64> {
+        self.record(Call::CreateRequest {
+            client_id,
+            request: Box::new(request.clone()),
+        });
+        self.inner.create_request(client_id, request).await
+    }
+
+    async fn create_service_hours(&self, entry: &NewServiceHours) -> Result<()> {
+        self.record(Call::CreateServiceHours(entry.clone()));
+        self.inner.create_service_hours(entry).await
+    }
+
+    async fn fetch_pending_assistance(&self) -> Result<Vec<PendingAssistance>> {
+        self.record(Call::FetchPendingAssistance);
+        self.inner.fetch_pending_assistance().await
+    }
+
+    async fn mark_assistance_paid(
+        &self,
+        request_id: u64,
+        item_id: u64,
+        date_paid: &str,
+        check_number: &str,
+    ) -> Result<()> {
+        self.record(Call::MarkAssistancePaid {
+            request_id,
+            item_id,
+            date_paid: date_paid.to_string(),
+            check_number: check_number.to_string(),
+        });
+        self.inner
+            .mark_assistance_paid(request_id, item_id, date_paid, check_number)
+            .await
+    }
+
+    async fn fetch_activity_report(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<ReportRow>> {
+        self.record(Call::FetchActivityReport { from, to });
+        self.inner.fetch_activity_report(from, to).await
+    }
+
+    async fn fetch_statistics(&self) -> Result<Vec<Statistic>> {
+        self.record(Call::FetchStatistics);
+        self.inner.fetch_statistics().await
+    }
+
+    async fn fetch_item_value(
+        &self,
+        assistance_type_id: &str,
+        client_id: u64,
+    ) -> Result<ItemValue> {
+        self.record(Call::FetchItemValue {
+            assistance_type_id: assistance_type_id.to_string(),
+            client_id,
+        });
+        self.inner
+            .fetch_item_value(assistance_type_id, client_id)
+            .await
+    }
+
+    async fn fetch_approvals(&self, request_id: u64) -> Result<Vec<Approval>> {
+        self.record(Call::FetchApprovals { request_id });
+        self.inner.fetch_approvals(request_id).await
+    }
+
+    fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+
+    async fn fetch_lookups(&self, request_id: u64) -> Result<Lookups> {
+        self.record(Call::FetchLookups { request_id });
+        self.inner.fetch_lookups(request_id).await
+    }
+
+    async fn search_clients(&self, query: &str) -> Result<Vec<Client>> {
+        self.record(Call::SearchClients(query.to_string()));
+        self.inner.search_clients(query).await
+    }
+
+    async fn open_follow_ups(&self) -> Result<Vec<FollowUp>> {
+        self.record(Call::FetchOpenFollowUps);
+        self.inner.open_follow_ups().await
+    }
+
+    async fn create_follow_up(&self, client_id: u64, follow_up: &NewFollowUp) -> Result<()> {
+        self.record(Call::CreateFollowUp {
+            client_id,
+            follow_up: follow_up.clone(),
+        });
+        self.inner.create_follow_up(client_id, follow_up).await
+    }
+
+    async fn fetch_calendar(
+        &self,
+        feed: CalendarFeed,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<CalendarEvent>> {
+        self.record(Call::FetchCalendar { feed, start, end });
+        self.inner.fetch_calendar(feed, start, end).await
+    }
+}
@@ -0,0 +1,261 @@
+use reqwest::Url;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::header::HeaderMap;
+use reqwest::header::HeaderValue;
+use reqwest::multipart;
+use scraper::Html;
+use scraper::Selector;
+
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::error::ResultExt;
+use super::scrape;
+use super::update_request::UpdateRequestInput;
+use super::update_request::build_update_form;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// A file attached to an assistance request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attachment {
+    /// ServWare's ID for the file, taken from its download link.
+    pub id: String,
+    /// File name as shown in the attachment list.
+    pub name: String,
+    /// Download link as it appears in the list, usually a path such as
+    /// `/app/assistancerequests/3724739/files/123`.
+    pub href: String,
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// List the files attached to a request.
+    ///
+    /// ServWare returns the list as an HTML fragment; every link into the
+    /// request's `files/` path counts as one attachment.
+    pub async fn fetch_attachments(&self, request_id: u64) -> Result<Vec<Attachment>> {
+        let url = self.request_files_url(request_id);
+        tracing::debug!(%url, "fetching attachment list");
+
+        let response = self
+            .send(|client| {
+                client
+                    .get(&url)
+                    .header("X-Requested-With", "XMLHttpRequest")
+            })
+            .await
+            .context("fetch attachments HTTP request failed")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ServWareError::status("fetch attachments", status));
+        }
+
+        let html = response
+            .text()
+            .await
+            .context("failed to read attachment list body")?;
+
+        let attachments = self::parse_attachments(&html);
+        tracing::debug!(count = attachments.len(), "parsed attachment list");
+        Ok(attachments)
+    }
+
+    /// Download the contents of an attachment.
+    pub async fn download_attachment(&self, attachment: &Attachment) -> Result<Vec<u8>> {
+        let url = Url::parse(self.base_url())
+            .and_then(|base| base.join(&attachment.href))
+            .map_err(|e| {
+                ServWareError::InvalidInput(format!(
+                    "invalid attachment link {}: {e}",
+                    attachment.href
+                ))
+            })?;
+        tracing::debug!(%url, "downloading attachment");
+
+        let response = self
+            .send(|client| client.get(url.clone()))
+            .await
+            .context("download attachment HTTP request failed")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ServWareError::status("download attachment", status));
+        }
+
+        let bytes = response
+            .bytes()
+            .await
+            .context("failed to read attachment body")?;
+        Ok(bytes.to_vec())
+    }
+
+    /// Attach a file (a signed receipt, a delivery photo, ...) to a request.
+    ///
+    /// ServWare takes uploads through the `files` field of the request edit
+    /// form, so this re-posts the current form unchanged with the file added.
+    pub async fn upload_attachment(
+        &self,
+        request_id: u64,
+        file_name: &str,
+        contents: Vec<u8>,
+    ) -> Result<()> {
+        let current = self
+            .fetch_request_detail(request_id)
+            .await
+            .context("failed to fetch current request state")?;
+        let fields = build_update_form(&current, &UpdateRequestInput::default());
+
+        let mime = mime_guess::from_path(file_name).first_or_octet_stream();
+        let url = self.request_url(request_id);
+        tracing::debug!(url, file_name, %mime, size = contents.len(), "uploading attachment");
+
+        let response = self
+            .send(|client| {
+                let mut form = multipart::Form::new();
+                let mut file = Some(self::file_part(&contents, file_name, mime.as_ref()));
+                for (name, value) in &fields {
+                    // The form has two `files` inputs; the file goes in the
+                    // first and the other stays empty, as a browser sends it.
+                    form = match file.take_if(|_| name == "files") {
+                        Some(file) => form.part("files", file),
+                        None => form.text(name.clone(), value.clone()),
+                    };
+                }
+                client.post(&url).multipart(form)
+            })
+            .await
+            .context("upload attachment POST failed")?;
+
+        scrape::check_saved(response, "upload attachment").await?;
+
+        tracing::info!(request_id, file_name, "attachment uploaded");
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// The `files` part of an upload. `mime` is guessed from the file name; if
+/// it somehow doesn't parse, the file goes up as
+/// `application/octet-stream` rather than failing the upload.
+fn file_part(contents: &[u8], file_name: &str, mime: &str) -> multipart::Part {
+    let part = || multipart::Part::bytes(contents.to_vec()).file_name(file_name.to_string());
+    part().mime_str(mime).unwrap_or_else(|_| {
+        tracing::warn!(mime, file_name, "bad MIME type, sending as octet-stream");
+        part().headers(HeaderMap::from_iter([(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/octet-stream"),
+        )]))
+    })
+}
+
+fn parse_attachments(html: &str) -> Vec<Attachment> {
+    let fragment = Html::parse_fragment(html);
+    let links = Selector::parse("a[href]").expect("valid CSS selector");
+
+    let mut attachments: Vec<Attachment> = Vec::new();
+    for link in fragment.select(&links) {
+        let href = link.value().attr("href").unwrap_or_default();
+        let Some(id) = self::file_id(href) else {
+            continue;
+        };
+        // Rows can also link to e.g. `files/{id}/delete`; the first link
+        // for a file is its download link.
+        if attachments.iter().any(|a| a.id == id) {
+            continue;
+        }
+        let name = link.text().collect::<String>().trim().to_string();
+        attachments.push(Attachment {
+            id: id.to_string(),
+            name,
+            href: href.to_string(),
+        });
+    }
+    attachments
+}
+
+/// `/app/assistancerequests/1/files/123` → `Some("123")`.
+fn file_id(href: &str) -> Option<&str> {
+    let (_, rest) = href.split_once("/files/")?;
+    let id = rest.split(['/', '?', '#']).next()?;
+    (!id.is_empty() && id != "list").then_some(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Attachment;
+    use super::file_id;
+    use super::parse_attachments;
+
+    fn table(rows: &str) -> String {
+        format!(
+            "<table><thead><tr><th>File</th><th></th></tr></thead><tbody>{rows}</tbody></table>"
+        )
+    }
+
+    #[test]
+    fn reads_one_attachment_per_file() {
+        let html = table(
+            r#"<tr><td><a href="/app/assistancerequests/3724739/files/123"> receipt.pdf </a></td>
+                <td><a href="/app/assistancerequests/3724739/files/123/delete">Delete</a></td></tr>
+               <tr><td><a href="/app/assistancerequests/3724739/files/124?download=true">photo.jpg</a></td>
+                <td><a href="/app/assistancerequests/3724739/files/124/delete">Delete</a></td></tr>"#,
+        );
+
+        assert_eq!(
+            parse_attachments(&html),
+            [
+                Attachment {
+                    id: "123".into(),
+                    name: "receipt.pdf".into(),
+                    href: "/app/assistancerequests/3724739/files/123".into(),
+                },
+                Attachment {
+                    id: "124".into(),
+                    name: "photo.jpg".into(),
+                    href: "/app/assistancerequests/3724739/files/124?download=true".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_table_has_no_attachments() {
+        assert!(parse_attachments(&table("")).is_empty());
+        assert!(parse_attachments("").is_empty());
+    }
+
+    #[test]
+    fn row_without_a_download_link_is_skipped() {
+        let html = table(
+            r#"<tr><td>scan.pdf</td><td><a href="/app/assistancerequests/3724739">Back</a></td></tr>
+               <tr><td><a href="/app/assistancerequests/3724739/files/list">Refresh</a></td></tr>"#,
+        );
+        assert!(parse_attachments(&html).is_empty());
+    }
+
+    #[test]
+    fn file_id_is_the_segment_after_files() {
+        assert_eq!(file_id("/app/assistancerequests/1/files/123"), Some("123"));
+        assert_eq!(
+            file_id("/app/assistancerequests/1/files/123/delete"),
+            Some("123")
+        );
+        assert_eq!(
+            file_id("/app/assistancerequests/1/files/123#top"),
+            Some("123")
+        );
+        assert_eq!(file_id("/app/assistancerequests/1/files/"), None);
+        assert_eq!(file_id("/app/assistancerequests/1/files/list"), None);
+        assert_eq!(file_id("/app/assistancerequests/1"), None);
+    }
+}
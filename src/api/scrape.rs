@@ -0,0 +1,362 @@
+use reqwest::Response;
+use reqwest::Url;
+use scraper::ElementRef;
+use scraper::Html;
+use scraper::Selector;
+
+use super::FieldError;
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::error::ResultExt;
+
+impl ServWare {
+    /// GET an HTML page and return its body. `what` names the page in errors.
+    pub(crate) async fn fetch_page(&self, url: &str, what: &str) -> Result<String> {
+        tracing::debug!(%url, "fetching {what}");
+
+        let response = self
+            .send(|client| client.get(url))
+            .await
+            .context(format!("failed to fetch {what}"))?;
+
+        let status = response.status();
+        if !status.is_success() && !status.is_redirection() {
+            return Err(ServWareError::status(&format!("fetch {what}"), status));
+        }
+
+        response
+            .text()
+            .await
+            .context(format!("failed to read {what} body"))
+    }
+}
+
+/// Check the response to a form POST. `what` names the form in errors.
+///
+/// ServWare redirects once a form is saved. When validation fails, Spring
+/// MVC answers 200 with the form shown again and the messages next to the
+/// fields, so a successful status alone doesn't mean anything was saved.
+pub(crate) async fn check_saved(response: Response, what: &str) -> Result<()> {
+    let status = response.status();
+    if !status.is_success() && !status.is_redirection() {
+        return Err(ServWareError::status(what, status));
+    }
+
+    let html = response
+        .text()
+        .await
+        .context(format!("failed to read {what} response"))?;
+    let errors = self::field_errors(&Html::parse_document(&html));
+    if !errors.is_empty() {
+        return Err(ServWareError::Validation {
+            context: what.to_string(),
+            errors,
+        });
+    }
+    Ok(())
+}
+
+/// The messages of Spring's `<form:errors>` tags, which render as
+/// `<span id="{field}.errors">` with one message per line.
+fn field_errors(document: &Html) -> Vec<FieldError> {
+    let spans = Selector::parse(r#"[id$=".errors"]"#).expect("valid CSS selector");
+
+    let mut errors = Vec::new();
+    for span in document.select(&spans) {
+        let field = span
+            .value()
+            .id()
+            .and_then(|id| id.strip_suffix(".errors"))
+            .filter(|field| !field.is_empty() && *field != "*")
+            .map(str::to_string);
+        for line in span.text() {
+            let message = line.split_whitespace().collect::<Vec<_>>().join(" ");
+            if !message.is_empty() {
+                errors.push(FieldError {
+                    field: field.clone(),
+                    message,
+                });
+            }
+        }
+    }
+    errors
+}
+
+/// `(value, label)` for every option of `<select id="{select_id}">`,
+/// skipping the empty "-- Select --" placeholder.
+///
+/// `None` if the page has no such select, which usually means the page
+/// structure changed.
+pub(crate) fn select_options(document: &Html, select_id: &str) -> Option<Vec<(String, String)>> {
+    let select = Selector::parse(&format!("select#{select_id}")).expect("valid CSS selector");
+    let option = Selector::parse("option").expect("valid CSS selector");
+
+    let select = document.select(&select).next()?;
+    let options = select
+        .select(&option)
+        .filter_map(|el| {
+            let value = el.value().attr("value")?.trim().to_string();
+            if value.is_empty() {
+                return None;
+            }
+            let label = el.text().collect::<String>().trim().to_string();
+            Some((value, label))
+        })
+        .collect();
+    Some(options)
+}
+
+/// The values a browser would submit for the first form matching the CSS
+/// selector `form`, in document order. A name repeats once per value, e.g.
+/// for a multi-select; disabled controls are left out.
+///
+/// `None` if the page has no such form, which usually means the page
+/// structure changed.
+pub(crate) fn form_values(document: &Html, form: &str) -> Option<Vec<(String, String)>> {
+    let form = Selector::parse(form).expect("valid CSS selector");
+    let controls =
+        Selector::parse("input[name], textarea[name], select[name]").expect("valid CSS selector");
+
+    let form = document.select(&form).next()?;
+    let mut fields = Vec::new();
+    for control in form.select(&controls) {
+        let element = control.value();
+        let Some(name) = element.attr("name") else {
+            continue;
+        };
+        if self::is_disabled(control) {
+            continue;
+        }
+        let values = match element.name() {
+            "textarea" => vec![control.text().collect::<String>()],
+            "select" => self::selected_options(control),
+            _ => match element.attr("type").unwrap_or("text") {
+                "checkbox" | "radio" => element
+                    .attr("checked")
+                    .map(|_| element.attr("value").unwrap_or("on").to_string())
+                    .into_iter()
+                    .collect(),
+                "submit" | "button" | "image" | "reset" | "file" => Vec::new(),
+                _ => vec![element.attr("value").unwrap_or_default().to_string()],
+            },
+        };
+        fields.extend(values.into_iter().map(|value| (name.to_string(), value)));
+    }
+    Some(fields)
+}
+
+/// The first value submitted under `name`, e.g. from [`form_values`].
+pub(crate) fn form_value<'a>(fields: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    fields
+        .iter()
+        .find(|(n, _)| n == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Disabled itself or inside a disabled `<fieldset>`; browsers submit
+/// neither.
+fn is_disabled(control: ElementRef<'_>) -> bool {
+    control.value().attr("disabled").is_some()
+        || control
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .any(|a| a.value().name() == "fieldset" && a.value().attr("disabled").is_some())
+}
+
+/// The values a browser would submit for a select: every selected option
+/// of a multi-select, else the selected option or the first one if none is
+/// marked selected. Disabled options are never submitted.
+fn selected_options(select: ElementRef<'_>) -> Vec<String> {
+    let options = Selector::parse("option").expect("valid CSS selector");
+    let options: Vec<ElementRef<'_>> = select
+        .select(&options)
+        .filter(|o| o.value().attr("disabled").is_none())
+        .collect();
+    let mut selected = options
+        .iter()
+        .filter(|o| o.value().attr("selected").is_some());
+
+    let chosen: Vec<&ElementRef<'_>> = if select.value().attr("multiple").is_some() {
+        selected.collect()
+    } else {
+        selected.next().or(options.first()).into_iter().collect()
+    };
+    chosen
+        .into_iter()
+        .map(|option| match option.value().attr("value") {
+            Some(value) => value.trim().to_string(),
+            None => option.text().collect::<String>().trim().to_string(),
+        })
+        .collect()
+}
+
+/// Cell text with runs of whitespace collapsed.
+pub(crate) fn cell_text(cell: ElementRef<'_>) -> String {
+    cell.text()
+        .flat_map(str::split_whitespace)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A dollar figure as ServWare displays it, e.g. `"$1,250.00"` → `1250.0`.
+pub(crate) fn parse_amount(value: &str) -> Option<f64> {
+    value.replace(['$', ','], "").trim().parse().ok()
+}
+
+/// ServWare redirects a saved form to the new record's detail page, e.g.
+/// `/app/assistancerequests/3724739`; the ID is its last path segment.
+pub(crate) fn created_id(url: &Url, prefix: &str) -> Option<u64> {
+    url.path().strip_prefix(prefix)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Response;
+    use scraper::Html;
+
+    use super::FieldError;
+    use super::ServWareError;
+    use super::check_saved;
+    use super::form_value;
+    use super::form_values;
+
+    fn response(status: u16, body: &str) -> Response {
+        http::Response::builder()
+            .status(status)
+            .body(body.to_string())
+            .unwrap()
+            .into()
+    }
+
+    fn error(field: Option<&str>, message: &str) -> FieldError {
+        FieldError {
+            field: field.map(str::to_string),
+            message: message.into(),
+        }
+    }
+
+    #[test]
+    fn form_values_are_what_a_browser_submits() {
+        let html = r#"<input name="outside" value="ignored">
+            <form action="/app/clients/search"><input name="lastName" value="search box"></form>
+            <form id="client" action="/app/clients/11">
+              <input name="lastName" value="Lopez">
+              <input name="householdIncome">
+              <textarea name="notes">Call first</textarea>
+              <select name="stateCode"><option value="">--</option><option value="CA" selected>California</option></select>
+              <select name="gender"><option>Female</option><option>Male</option></select>
+              <input type="checkbox" name="veteran" checked><input type="hidden" name="_veteran" value="on">
+              <input type="checkbox" name="homeless" value="true">
+              <input type="radio" name="language" value="en"><input type="radio" name="language" value="es" checked>
+              <input type="file" name="files"><input type="submit" name="action" value="save">
+            </form>"#;
+
+        let fields = form_values(&Html::parse_document(html), "form#client").unwrap();
+        let get = |name: &str| form_value(&fields, name);
+        assert_eq!(get("lastName"), Some("Lopez"));
+        assert_eq!(get("householdIncome"), Some(""));
+        assert_eq!(get("notes"), Some("Call first"));
+        assert_eq!(get("stateCode"), Some("CA"));
+        assert_eq!(get("gender"), Some("Female"));
+        assert_eq!(get("veteran"), Some("on"));
+        assert_eq!(get("_veteran"), Some("on"));
+        assert_eq!(get("language"), Some("es"));
+        for skipped in ["outside", "homeless", "files", "action"] {
+            assert_eq!(get(skipped), None, "{skipped}");
+        }
+        assert_eq!(fields.iter().filter(|(n, _)| n == "lastName").count(), 1);
+    }
+
+    #[test]
+    fn repeated_names_keep_every_value() {
+        let html = r#"<form>
+              <input type="hidden" name="memberIds" value="1">
+              <input type="hidden" name="memberIds" value="2">
+              <select name="languages" multiple>
+                <option value="en" selected>English</option>
+                <option value="fr">French</option>
+                <option value="es" selected>Spanish</option>
+              </select>
+              <select name="none" multiple><option value="x">X</option></select>
+            </form>"#;
+
+        let fields = form_values(&Html::parse_document(html), "form").unwrap();
+        let pairs: Vec<(&str, &str)> = fields
+            .iter()
+            .map(|(n, v)| (n.as_str(), v.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                ("memberIds", "1"),
+                ("memberIds", "2"),
+                ("languages", "en"),
+                ("languages", "es"),
+            ]
+        );
+    }
+
+    #[test]
+    fn disabled_controls_are_not_submitted() {
+        let html = r#"<form>
+              <input name="firstName" value="Ana">
+              <input name="clientId" value="11" disabled>
+              <fieldset disabled><input name="income" value="900"></fieldset>
+              <select name="status"><option value="Open" disabled>Open</option><option value="Closed">Closed</option></select>
+            </form>"#;
+
+        let fields = form_values(&Html::parse_document(html), "form").unwrap();
+        assert_eq!(form_value(&fields, "firstName"), Some("Ana"));
+        assert_eq!(form_value(&fields, "clientId"), None);
+        assert_eq!(form_value(&fields, "income"), None);
+        assert_eq!(form_value(&fields, "status"), Some("Closed"));
+    }
+
+    #[test]
+    fn missing_form_is_none() {
+        let html = r#"<form action="/app/clients/search"><input name="q"></form>"#;
+        assert!(form_values(&Html::parse_document(html), "form#client").is_none());
+    }
+
+    #[tokio::test]
+    async fn redirect_or_clean_page_is_saved() {
+        check_saved(response(302, ""), "save").await.unwrap();
+        check_saved(response(200, "<form><input name=\"a\"></form>"), "save")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn field_and_global_errors_are_reported_together() {
+        let html = r#"<form>
+            <span id="*.errors" class="error">Request could not be saved<br>Check the dates</span>
+            <input name="monetaryValue" value="abc">
+            <span id="monetaryValue.errors" class="error">  must be
+              a number </span>
+        </form>"#;
+
+        let err = check_saved(response(200, html), "add assistance")
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, ServWareError::Validation { context, .. } if context == "add assistance")
+        );
+        assert_eq!(
+            err.field_errors(),
+            [
+                error(None, "Request could not be saved"),
+                error(None, "Check the dates"),
+                error(Some("monetaryValue"), "must be a number"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn error_status_is_not_saved() {
+        let err = check_saved(response(500, "oops"), "save")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ServWareError::Status { .. }));
+    }
+}
@@ -0,0 +1,183 @@
+use chrono::NaiveDate;
+use futures_util::Stream;
+use futures_util::TryStreamExt;
+use serde::Deserialize;
+
+use super::Result;
+use super::ServWare;
+use super::datatables;
+use super::datatables::DataTablesResponse;
+use super::error::ResultExt;
+use super::scrape;
+
+/// How ServWare writes service dates, e.g. `03/15/2026`.
+const SERVICE_DATE_FORMAT: &str = "%m/%d/%Y";
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Hours and mileage a member logged for service not tied to a request,
+/// e.g. a pantry shift or a conference meeting.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ServiceHours {
+    pub id: u64,
+    pub member_id: String,
+    /// The member's name as ServWare shows it.
+    pub member: Option<String>,
+    /// `MM/DD/YYYY`.
+    pub date_of_service: String,
+    pub hours_in_service: f64,
+    pub mileage_in_service: f64,
+    pub notes: String,
+}
+
+impl ServiceHours {
+    pub fn service_date(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(&self.date_of_service, SERVICE_DATE_FORMAT).ok()
+    }
+}
+
+/// DataTables envelope of the list behind `/app/mileagehoursinservicelist`.
+pub type FetchServiceHoursResponse = DataTablesResponse<ServiceHours>;
+
+/// Parameters for listing mileage and hours entries.
+#[derive(Debug, Clone)]
+pub struct FetchServiceHoursParams {
+    pub display_start: u32,
+    pub display_length: u32,
+}
+
+impl FetchServiceHoursParams {
+    /// Records requested per page unless the caller picks another size.
+    pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
+    /// Config for every entry, oldest first.
+    pub fn new_all() -> Self {
+        Self {
+            display_start: 0,
+            display_length: Self::DEFAULT_PAGE_SIZE,
+        }
+    }
+}
+
+/// Fields for a new mileage and hours entry.
+#[derive(Debug, Clone)]
+pub struct NewServiceHours {
+    pub member_id: String,
+    /// `MM/DD/YYYY`.
+    pub date_of_service: String,
+    pub hours_in_service: String,
+    pub mileage_in_service: String,
+    pub notes: String,
+}
+
+impl NewServiceHours {
+    /// An entry for `member_id` on `date`, without notes.
+    pub fn new(member_id: impl Into<String>, date: NaiveDate, hours: f64, miles: f64) -> Self {
+        Self {
+            member_id: member_id.into(),
+            date_of_service: date.format(SERVICE_DATE_FORMAT).to_string(),
+            hours_in_service: hours.to_string(),
+            mileage_in_service: miles.to_string(),
+            notes: String::new(),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Fetch one page of mileage and hours entries.
+    pub async fn fetch_service_hours(
+        &self,
+        params: &FetchServiceHoursParams,
+    ) -> Result<FetchServiceHoursResponse> {
+        let url = datatables::page_url(
+            &self.service_hours_url(),
+            &[
+                "dateOfService",
+                "member",
+                "hoursInService",
+                "mileageInService",
+                "notes",
+            ],
+            params.display_start,
+            params.display_length,
+            &[
+                ("iSortCol_0", "0"),
+                ("sSortDir_0", "asc"),
+                ("iSortingCols", "1"),
+                ("sSearch", ""),
+                ("bRegex", "false"),
+            ],
+        )?;
+
+        self.fetch_data_table(url, "mileage and hours").await
+    }
+
+    /// Yield every mileage and hours entry, one page at a time.
+    pub fn stream_service_hours(
+        &self,
+        params: FetchServiceHoursParams,
+    ) -> impl Stream<Item = Result<ServiceHours>> + Send + '_ {
+        let (start, length) = (params.display_start, params.display_length);
+        datatables::paginate(start, length, move |display_start| {
+            let params = FetchServiceHoursParams {
+                display_start,
+                ..params.clone()
+            };
+            async move { self.fetch_service_hours(&params).await }
+        })
+    }
+
+    /// Entries dated `from` through `to`, inclusive, e.g. one month's for
+    /// the hours report.
+    pub async fn service_hours_between(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<ServiceHours>> {
+        self.stream_service_hours(FetchServiceHoursParams::new_all())
+            .try_filter(|entry| {
+                let dated = entry.service_date();
+                std::future::ready(dated.is_some_and(|date| from <= date && date <= to))
+            })
+            .try_collect()
+            .await
+    }
+
+    /// Log hours and mileage for a member.
+    ///
+    /// Fails with [`ServWareError::Validation`](super::ServWareError) if
+    /// ServWare shows the form again with error messages instead of saving.
+    pub async fn create_service_hours(&self, entry: &NewServiceHours) -> Result<()> {
+        let form = [
+            ("memberId", entry.member_id.as_str()),
+            ("dateOfService", entry.date_of_service.as_str()),
+            ("hoursInService", entry.hours_in_service.as_str()),
+            ("mileageInService", entry.mileage_in_service.as_str()),
+            ("notes", entry.notes.as_str()),
+        ];
+        let url = self.new_service_hours_url();
+
+        tracing::debug!(url, "posting mileage and hours");
+
+        let response = self
+            .send(|client| client.post(&url).form(&form))
+            .await
+            .context("create mileage and hours POST failed")?;
+
+        scrape::check_saved(response, "create mileage and hours").await?;
+
+        tracing::info!(
+            member_id = entry.member_id,
+            date = entry.date_of_service,
+            "mileage and hours logged"
+        );
+        Ok(())
+    }
+}
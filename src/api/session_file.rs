@@ -0,0 +1,295 @@
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+
+use anyhow::Context;
+use anyhow::bail;
+use argon2::Argon2;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chacha20poly1305::ChaCha20Poly1305;
+use chacha20poly1305::Nonce;
+use chacha20poly1305::aead::Aead;
+use chacha20poly1305::aead::AeadCore;
+use chacha20poly1305::aead::KeyInit;
+use chacha20poly1305::aead::OsRng;
+use chacha20poly1305::aead::rand_core::RngCore;
+use cookie_store::RawCookie;
+use reqwest::Url;
+use reqwest::header::HeaderValue;
+use secrecy::ExposeSecret;
+use secrecy::SecretString;
+use serde::Deserialize;
+use serde::Serialize;
+
+const SALT_LEN: usize = 16;
+
+// ---------------------------------------------------------------------------
+// Cookie jar
+// ---------------------------------------------------------------------------
+
+/// Cookie jar for the HTTP client that can also be written to disk.
+///
+/// Behaves like `reqwest::cookie::Jar`, which keeps its store private and so
+/// can't be saved.
+#[derive(Default)]
+pub(crate) struct CookieJar(Mutex<cookie_store::CookieStore>);
+
+impl CookieJar {
+    fn lock(&self) -> MutexGuard<'_, cookie_store::CookieStore> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Serialize every cookie, including ServWare's session cookie, which
+    /// has no expiry and would otherwise be skipped.
+    fn to_json(&self) -> anyhow::Result<String> {
+        let mut buf = Vec::new();
+        cookie_store::serde::json::save_incl_expired_and_nonpersistent(&self.lock(), &mut buf)
+            .map_err(|e| anyhow::anyhow!("failed to serialize cookies: {e}"))?;
+        String::from_utf8(buf).context("serialized cookies are not UTF-8")
+    }
+
+    fn from_json(json: &str) -> anyhow::Result<Self> {
+        let store = cookie_store::serde::json::load(json.as_bytes())
+            .map_err(|e| anyhow::anyhow!("failed to parse saved cookies: {e}"))?;
+        Ok(Self(Mutex::new(store)))
+    }
+}
+
+impl reqwest::cookie::CookieStore for CookieJar {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &Url) {
+        let cookies = cookie_headers
+            .filter_map(|v| v.to_str().ok())
+            .filter_map(|v| RawCookie::parse(v.to_string()).ok());
+        self.lock().store_response_cookies(cookies, url);
+    }
+
+    fn cookies(&self, url: &Url) -> Option<HeaderValue> {
+        let header = self
+            .lock()
+            .get_request_values(url)
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        if header.is_empty() {
+            return None;
+        }
+        HeaderValue::from_str(&header).ok()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Session file
+// ---------------------------------------------------------------------------
+
+/// What a session file holds on disk.
+#[derive(Serialize, Deserialize)]
+struct SavedSession {
+    base_url: String,
+    username: String,
+    saved_at: String,
+    cookies: SavedCookies,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum SavedCookies {
+    Plain {
+        json: String,
+    },
+    /// ChaCha20-Poly1305 over the cookie JSON, keyed by Argon2id of the
+    /// passphrase. All fields are base64.
+    Encrypted {
+        salt: String,
+        nonce: String,
+        ciphertext: String,
+    },
+}
+
+/// A session restored from disk.
+pub(crate) struct LoadedSession {
+    pub base_url: String,
+    pub username: String,
+    pub cookies: CookieJar,
+}
+
+/// Where a logged-in session is kept between CLI runs.
+///
+/// The file is only readable by the current user (0600 on Unix). With a
+/// passphrase the cookies are also encrypted, so a copied file is useless
+/// without it.
+pub struct SessionFile {
+    path: PathBuf,
+    passphrase: Option<SecretString>,
+}
+
+impl SessionFile {
+    pub fn new(path: impl Into<PathBuf>, passphrase: Option<SecretString>) -> Self {
+        Self {
+            path: path.into(),
+            passphrase,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether the file on disk is encrypted but there's no passphrase to
+    /// open it with, e.g. `--encrypt-session` was left off this time.
+    pub fn is_locked(&self) -> bool {
+        self.passphrase.is_none() && self.is_encrypted()
+    }
+
+    /// Whether the file on disk holds encrypted cookies. `false` if there's
+    /// no file or it can't be read.
+    fn is_encrypted(&self) -> bool {
+        fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|contents| serde_json::from_str::<SavedSession>(&contents).ok())
+            .is_some_and(|saved| matches!(saved.cookies, SavedCookies::Encrypted { .. }))
+    }
+
+    pub(crate) fn save(
+        &self,
+        base_url: &str,
+        username: &str,
+        jar: &CookieJar,
+    ) -> anyhow::Result<()> {
+        // Writing plain cookies over an encrypted file would quietly undo
+        // the encryption.
+        if self.is_locked() {
+            bail!(
+                "session file {} is encrypted; not overwriting it without the passphrase",
+                self.path.display()
+            );
+        }
+
+        let json = jar.to_json()?;
+        let cookies = match &self.passphrase {
+            Some(passphrase) => self::seal(passphrase, &json)?,
+            None => SavedCookies::Plain { json },
+        };
+        let saved = SavedSession {
+            base_url: base_url.to_string(),
+            username: username.to_string(),
+            saved_at: chrono::Local::now().to_rfc3339(),
+            cookies,
+        };
+        let contents = serde_json::to_string_pretty(&saved)?;
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options
+            .open(&self.path)
+            .with_context(|| format!("failed to open session file {}", self.path.display()))?;
+
+        // `mode` only applies when the file is created; tighten an old one too.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(fs::Permissions::from_mode(0o600))?;
+        }
+
+        file.write_all(contents.as_bytes())
+            .with_context(|| format!("failed to write session file {}", self.path.display()))?;
+        tracing::debug!(path = %self.path.display(), "saved session");
+        Ok(())
+    }
+
+    /// Read the session back, or `None` if the file doesn't exist.
+    pub(crate) fn load(&self) -> anyhow::Result<Option<LoadedSession>> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(e).with_context(|| {
+                    format!("failed to read session file {}", self.path.display())
+                });
+            }
+        };
+        let saved: SavedSession =
+            serde_json::from_str(&contents).context("session file is not valid")?;
+
+        let json = match (saved.cookies, &self.passphrase) {
+            (SavedCookies::Plain { json }, _) => json,
+            (
+                SavedCookies::Encrypted {
+                    salt,
+                    nonce,
+                    ciphertext,
+                },
+                Some(passphrase),
+            ) => self::open(passphrase, &salt, &nonce, &ciphertext)?,
+            (SavedCookies::Encrypted { .. }, None) => {
+                bail!("session file is encrypted but no passphrase was given")
+            }
+        };
+
+        Ok(Some(LoadedSession {
+            base_url: saved.base_url,
+            username: saved.username,
+            cookies: CookieJar::from_json(&json)?,
+        }))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn cipher(passphrase: &SecretString, salt: &[u8]) -> anyhow::Result<ChaCha20Poly1305> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.expose_secret().as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow::anyhow!("failed to derive session key: {e}"))?;
+    Ok(ChaCha20Poly1305::new(&key.into()))
+}
+
+fn seal(passphrase: &SecretString, plaintext: &str) -> anyhow::Result<SavedCookies> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+    let ciphertext = self::cipher(passphrase, &salt)?
+        .encrypt(&nonce, plaintext.as_bytes())
+        .map_err(|_| anyhow::anyhow!("failed to encrypt session"))?;
+
+    Ok(SavedCookies::Encrypted {
+        salt: BASE64.encode(salt),
+        nonce: BASE64.encode(nonce),
+        ciphertext: BASE64.encode(ciphertext),
+    })
+}
+
+fn open(
+    passphrase: &SecretString,
+    salt: &str,
+    nonce: &str,
+    ciphertext: &str,
+) -> anyhow::Result<String> {
+    let salt = BASE64.decode(salt).context("bad salt in session file")?;
+    let nonce = BASE64.decode(nonce).context("bad nonce in session file")?;
+    let ciphertext = BASE64
+        .decode(ciphertext)
+        .context("bad ciphertext in session file")?;
+    if nonce.len() != 12 {
+        bail!("bad nonce length in session file");
+    }
+
+    let plaintext = self::cipher(passphrase, &salt)?
+        .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+        .map_err(|_| anyhow::anyhow!("failed to decrypt session file (wrong passphrase?)"))?;
+    String::from_utf8(plaintext).context("decrypted session is not UTF-8")
+}
@@ -1,6 +1,7 @@
-use anyhow::Context;
-
+use super::Result;
 use super::ServWare;
+use super::error::ResultExt;
+use super::scrape;
 
 // ---------------------------------------------------------------------------
 // Input
@@ -10,6 +11,7 @@ use super::ServWare;
 ///
 /// Mirrors the full form captured from the ServWare UI. All optional/unused
 /// fields are sent as empty strings to match browser behaviour.
+#[derive(Debug, Clone)]
 pub struct UpdateAssistanceInput {
     // Required
     pub assistance_type_id: String,
@@ -25,6 +27,10 @@ pub struct UpdateAssistanceInput {
     pub client_account_number: String,
     pub payee_name: String,
     pub check_requested: bool,
+    /// Promised but not yet paid; listed by
+    /// [`fetch_pending_assistance`](ServWare::fetch_pending_assistance) until
+    /// marked paid.
+    pub pending: bool,
 }
 
 impl UpdateAssistanceInput {
@@ -48,6 +54,7 @@ impl UpdateAssistanceInput {
             client_account_number: String::new(),
             payee_name: String::new(),
             check_requested: false,
+            pending: false,
         }
     }
 }
@@ -60,13 +67,15 @@ impl ServWare {
     /// Add an assistance item to a request.
     ///
     /// Sends the full form matching the browser's POST, including empty
-    /// optional fields, to avoid server-side validation issues.
+    /// optional fields, to avoid server-side validation issues. Fails with
+    /// [`ServWareError::Validation`](super::ServWareError::Validation) if
+    /// ServWare rejects the item anyway.
     pub async fn update_assistance(
         &self,
         request_id: u64,
         input: &UpdateAssistanceInput,
-    ) -> anyhow::Result<()> {
-        let url = Self::assistance_item_url(request_id);
+    ) -> Result<()> {
+        let url = self.assistance_item_url(request_id);
 
         let mut form: Vec<(&str, &str)> = vec![
             ("assistanceTypeId", &input.assistance_type_id),
@@ -85,11 +94,15 @@ impl ServWare {
             ("quantity", &input.quantity),
             ("dateProvided", &input.date_provided),
             ("voucherAsstId", ""),
-            ("_pending", "on"),
-            ("promisedDate", ""),
         ];
 
         // Spring MVC checkbox convention
+        if input.pending {
+            form.push(("pending", "true"));
+        }
+        form.push(("_pending", "on"));
+        form.push(("promisedDate", ""));
+
         if input.check_requested {
             form.push(("checkRequested", "true"));
         }
@@ -110,19 +123,12 @@ impl ServWare {
         tracing::debug!(url, request_id, "posting new assistance item");
 
         let response = self
-            .client
-            .post(&url)
-            .form(&form)
-            .send()
+            .send(|client| client.post(&url).form(&form))
             .await
             .context("add assistance item POST failed")?;
 
-        let status = response.status();
-        tracing::debug!(%status, "add assistance item response");
-
-        if !status.is_success() && !status.is_redirection() {
-            anyhow::bail!("add assistance item failed with status {status}");
-        }
+        tracing::debug!(status = %response.status(), "add assistance item response");
+        scrape::check_saved(response, "add assistance item").await?;
 
         tracing::info!(request_id, "assistance item added successfully");
         Ok(())
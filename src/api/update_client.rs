@@ -0,0 +1,311 @@
+use scraper::Html;
+
+use super::Result;
+use super::ServWare;
+use super::ServWareError;
+use super::error::ResultExt;
+use super::fetch_client_detail::ClientDetail;
+use super::fetch_client_detail::client_form;
+use super::scrape;
+use super::update_request::push_checkbox;
+
+// ---------------------------------------------------------------------------
+// Input
+// ---------------------------------------------------------------------------
+
+/// Fields to update on a neighbor record.
+///
+/// All fields are optional — `None` means "keep the current value from the
+/// server." Like [`UpdateRequestInput`](super::update_request::UpdateRequestInput),
+/// `update_client` fetches the current form and merges these overrides
+/// before POSTing it back. Every field of the form is posted back, including
+/// ones this struct doesn't cover (household members, income, custom
+/// fields).
+#[derive(Debug, Clone, Default)]
+pub struct UpdateClientInput {
+    // Name
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub middle_initial: Option<String>,
+    pub birth_date: Option<String>,
+
+    // Contact
+    pub home_phone: Option<String>,
+    pub work_phone: Option<String>,
+    pub mobile_phone: Option<String>,
+    pub email_address: Option<String>,
+    pub text_communication_preferred: Option<bool>,
+
+    // Address
+    pub street_address_line1: Option<String>,
+    pub street_address_line2: Option<String>,
+    pub city: Option<String>,
+    pub state_code: Option<String>,
+    pub postal_code: Option<String>,
+
+    // Status checkboxes
+    pub parishioner: Option<bool>,
+    pub homeless: Option<bool>,
+    pub disabled_client: Option<bool>,
+    pub veteran: Option<bool>,
+    pub private_client: Option<bool>,
+
+    // Notes
+    pub notes: Option<String>,
+    pub alert_note: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Form building
+// ---------------------------------------------------------------------------
+
+/// Build the client form from a [`ClientDetail`], for a neighbor that has
+/// no form on the server yet.
+pub(crate) fn build_client_form(
+    current: &ClientDetail,
+    input: &UpdateClientInput,
+) -> Vec<(String, String)> {
+    let mut f: Vec<(String, String)> = Vec::with_capacity(30);
+    let text = |value: &Option<String>, current: &str| {
+        value.clone().unwrap_or_else(|| current.to_string())
+    };
+
+    // --- Name ---
+    f.push((
+        "firstName".into(),
+        text(&input.first_name, &current.first_name),
+    ));
+    f.push((
+        "lastName".into(),
+        text(&input.last_name, &current.last_name),
+    ));
+    f.push((
+        "middleInitial".into(),
+        text(&input.middle_initial, &current.middle_initial),
+    ));
+    f.push((
+        "birthDate".into(),
+        text(&input.birth_date, &current.birth_date),
+    ));
+
+    // --- Contact ---
+    f.push((
+        "homePhone".into(),
+        text(&input.home_phone, &current.home_phone),
+    ));
+    f.push((
+        "workPhone".into(),
+        text(&input.work_phone, &current.work_phone),
+    ));
+    f.push((
+        "mobilePhone".into(),
+        text(&input.mobile_phone, &current.mobile_phone),
+    ));
+    f.push((
+        "emailAddress".into(),
+        text(&input.email_address, &current.email_address),
+    ));
+    push_checkbox(
+        &mut f,
+        "textCommunicationPreferred",
+        input
+            .text_communication_preferred
+            .unwrap_or(current.text_communication_preferred),
+    );
+
+    // --- Address ---
+    f.push((
+        "streetAddressLine1".into(),
+        text(&input.street_address_line1, &current.street_address_line1),
+    ));
+    f.push((
+        "streetAddressLine2".into(),
+        text(&input.street_address_line2, &current.street_address_line2),
+    ));
+    f.push(("city".into(), text(&input.city, &current.city)));
+    f.push((
+        "stateCode".into(),
+        text(&input.state_code, &current.state_code),
+    ));
+    f.push((
+        "postalCode".into(),
+        text(&input.postal_code, &current.postal_code),
+    ));
+
+    // --- Status checkboxes ---
+    push_checkbox(
+        &mut f,
+        "parishioner",
+        input.parishioner.unwrap_or(current.parishioner),
+    );
+    push_checkbox(
+        &mut f,
+        "homeless",
+        input.homeless.unwrap_or(current.homeless),
+    );
+    push_checkbox(
+        &mut f,
+        "disabledClient",
+        input.disabled_client.unwrap_or(current.disabled_client),
+    );
+    push_checkbox(&mut f, "veteran", input.veteran.unwrap_or(current.veteran));
+    push_checkbox(
+        &mut f,
+        "privateClient",
+        input.private_client.unwrap_or(current.private_client),
+    );
+
+    // --- Notes ---
+    f.push(("notes".into(), text(&input.notes, &current.notes)));
+    f.push((
+        "alertNote".into(),
+        text(&input.alert_note, &current.alert_note),
+    ));
+
+    f
+}
+
+/// Merge user input over every value of the client form scraped from the
+/// detail page, so fields [`ClientDetail`] doesn't model keep their values.
+/// Fields are posted in page order, repeated names included.
+pub(crate) fn merge_client_form(
+    mut current: Vec<(String, String)>,
+    input: &UpdateClientInput,
+) -> Vec<(String, String)> {
+    let text = [
+        ("firstName", &input.first_name),
+        ("lastName", &input.last_name),
+        ("middleInitial", &input.middle_initial),
+        ("birthDate", &input.birth_date),
+        ("homePhone", &input.home_phone),
+        ("workPhone", &input.work_phone),
+        ("mobilePhone", &input.mobile_phone),
+        ("emailAddress", &input.email_address),
+        ("streetAddressLine1", &input.street_address_line1),
+        ("streetAddressLine2", &input.street_address_line2),
+        ("city", &input.city),
+        ("stateCode", &input.state_code),
+        ("postalCode", &input.postal_code),
+        ("notes", &input.notes),
+        ("alertNote", &input.alert_note),
+    ];
+    for (name, value) in text {
+        if let Some(value) = value {
+            current.retain(|(n, _)| n != name);
+            current.push((name.into(), value.clone()));
+        }
+    }
+
+    // A checked box shows up in the scraped values; an unchecked one only
+    // has its `_name` companion.
+    let checkboxes = [
+        (
+            "textCommunicationPreferred",
+            input.text_communication_preferred,
+        ),
+        ("parishioner", input.parishioner),
+        ("homeless", input.homeless),
+        ("disabledClient", input.disabled_client),
+        ("veteran", input.veteran),
+        ("privateClient", input.private_client),
+    ]
+    .map(|(name, value)| {
+        let was_checked = scrape::form_value(&current, name).is_some();
+        let companion = format!("_{name}");
+        current.retain(|(n, _)| n != name && *n != companion);
+        (name, value.unwrap_or(was_checked))
+    });
+
+    for (name, checked) in checkboxes {
+        push_checkbox(&mut current, name, checked);
+    }
+    current
+}
+
+// ---------------------------------------------------------------------------
+// Implementation
+// ---------------------------------------------------------------------------
+
+impl ServWare {
+    /// Update a neighbor record using read-modify-write.
+    ///
+    /// 1. Fetches the current form from the client detail page
+    /// 2. Merges `input` fields over the current values
+    /// 3. POSTs the complete form
+    ///
+    /// Fails with [`ServWareError::Validation`] if ServWare shows the form
+    /// again with error messages instead of saving it.
+    pub async fn update_client(&self, client_id: u64, input: &UpdateClientInput) -> Result<()> {
+        tracing::debug!(client_id, "fetching current client state for update");
+        let url = self.client_url(client_id);
+        let html = self
+            .fetch_page(&url, "client detail page")
+            .await
+            .context("failed to fetch current client state")?;
+
+        let current = scrape::form_values(&Html::parse_document(&html), &client_form(client_id))
+            .filter(|fields| scrape::form_value(fields, "lastName").is_some())
+            .ok_or_else(|| {
+                ServWareError::PageChanged(format!("no client form found for client {client_id}"))
+            })?;
+        let form = merge_client_form(current, input);
+
+        tracing::debug!(url, fields = form.len(), "posting client update");
+
+        let response = self
+            .send(|client| client.post(&url).form(&form))
+            .await
+            .context("update client POST failed")?;
+
+        tracing::debug!(status = %response.status(), "update client response");
+        scrape::check_saved(response, "update client").await?;
+
+        tracing::info!(client_id, "client updated successfully");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateClientInput;
+    use super::merge_client_form;
+
+    fn pair(name: &str, value: &str) -> (String, String) {
+        (name.into(), value.into())
+    }
+
+    #[test]
+    fn merge_overrides_input_and_keeps_the_rest_of_the_form() {
+        let current = vec![
+            pair("firstName", "Ana"),
+            pair("lastName", "Lopez"),
+            pair("householdMemberIds", "7"),
+            pair("householdMemberIds", "8"),
+            pair("veteran", "true"),
+            pair("_veteran", "on"),
+            pair("_homeless", "on"),
+        ];
+        let input = UpdateClientInput {
+            last_name: Some("Lopez-Garcia".into()),
+            homeless: Some(true),
+            ..Default::default()
+        };
+
+        let form = merge_client_form(current, &input);
+        let get_all = |name: &str| -> Vec<&str> {
+            form.iter()
+                .filter(|(n, _)| n == name)
+                .map(|(_, v)| v.as_str())
+                .collect()
+        };
+        assert_eq!(get_all("firstName"), ["Ana"]);
+        assert_eq!(get_all("lastName"), ["Lopez-Garcia"]);
+        assert_eq!(get_all("householdMemberIds"), ["7", "8"]);
+        assert_eq!(get_all("veteran"), ["true"]);
+        assert_eq!(get_all("_veteran"), ["on"]);
+        assert_eq!(get_all("homeless"), ["true"]);
+        assert_eq!(get_all("_homeless"), ["on"]);
+        assert_eq!(get_all("parishioner"), Vec::<&str>::new());
+        assert_eq!(get_all("_parishioner"), ["on"]);
+    }
+}
@@ -1,9 +1,8 @@
-use anyhow::Context;
-
-use crate::api::fetch_requests::FetchRequestsParams;
-
+use super::Result;
 use super::ServWare;
-use super::fetch_requests::AssistanceRequest;
+use super::error::ResultExt;
+use super::fetch_request_detail::RequestDetail;
+use super::scrape;
 
 // ---------------------------------------------------------------------------
 // Input
@@ -12,14 +11,10 @@ use super::fetch_requests::AssistanceRequest;
 /// Fields to update on an assistance request.
 ///
 /// All fields are optional — `None` means "keep the current value from the
-/// server." Internally, `update_request` fetches the current state and merges
-/// these overrides before POSTing the full form.
-///
-/// **Member ID limitation:** The list API returns display names (e.g.
-/// `"John Smith"`) but the form requires numeric IDs. If you don't provide
-/// member IDs here, the corresponding form fields will be sent empty, which
-/// may clear the assignment on the server.
-#[derive(Debug, Default)]
+/// server." Internally, `update_request` fetches the current form from the
+/// request detail page and merges these overrides before POSTing it back, so
+/// untouched fields (including member assignments) keep their values.
+#[derive(Debug, Clone, Default)]
 pub struct UpdateRequestInput {
     // Status
     pub status: Option<String>,
@@ -71,7 +66,7 @@ pub struct UpdateRequestInput {
 ///
 /// - Checked: `name=true` + `_name=on`
 /// - Unchecked: `_name=on` only
-fn push_checkbox(form: &mut Vec<(String, String)>, name: &str, checked: bool) {
+pub(crate) fn push_checkbox(form: &mut Vec<(String, String)>, name: &str, checked: bool) {
     if checked {
         form.push((name.to_string(), "true".into()));
     }
@@ -80,68 +75,51 @@ fn push_checkbox(form: &mut Vec<(String, String)>, name: &str, checked: bool) {
 
 /// Build the full 30-field form by merging user input over the current server
 /// state.
-fn build_update_form(
-    current: &AssistanceRequest,
+pub(crate) fn build_update_form(
+    current: &RequestDetail,
     input: &UpdateRequestInput,
 ) -> Vec<(String, String)> {
     let mut f: Vec<(String, String)> = Vec::with_capacity(40);
+    let text = |value: &Option<String>, current: &str| {
+        value.clone().unwrap_or_else(|| current.to_string())
+    };
 
     // --- Status ---
-    f.push((
-        "status".into(),
-        input
-            .status
-            .clone()
-            .unwrap_or_else(|| current.status.clone()),
-    ));
+    f.push(("status".into(), text(&input.status, &current.status)));
     f.push((
         "denialReasonId".into(),
-        input.denial_reason_id.clone().unwrap_or_default(),
+        text(&input.denial_reason_id, &current.denial_reason_id),
     ));
     f.push((
         "denialReasonStr".into(),
-        input.denial_reason_str.clone().unwrap_or_default(),
+        text(&input.denial_reason_str, &current.denial_reason_str),
     ));
 
     // --- Client info ---
     f.push((
         "clientFirstName".into(),
-        input
-            .client_first_name
-            .clone()
-            .unwrap_or_else(|| current.client.first_name.clone()),
+        text(&input.client_first_name, &current.client_first_name),
     ));
     f.push((
         "clientLastName".into(),
-        input
-            .client_last_name
-            .clone()
-            .unwrap_or_else(|| current.client.last_name.clone()),
+        text(&input.client_last_name, &current.client_last_name),
     ));
     f.push((
         "dateRequested".into(),
-        input
-            .date_requested
-            .clone()
-            .unwrap_or_else(|| current.date_requested.clone()),
+        text(&input.date_requested, &current.date_requested),
     ));
 
     // --- Assignment ---
-    // Note: list API doesn't expose member IDs, only display names. If the
-    // user doesn't provide an ID, we send empty string.
     f.push((
         "requestAssignedToMemberId".into(),
-        input
-            .request_assigned_to_member_id
-            .clone()
-            .unwrap_or_default(),
+        text(
+            &input.request_assigned_to_member_id,
+            &current.request_assigned_to_member_id,
+        ),
     ));
     f.push((
         "requestNote".into(),
-        input
-            .request_note
-            .clone()
-            .unwrap_or_else(|| current.request_note.clone()),
+        text(&input.request_note, &current.request_note),
     ));
     f.push(("files".into(), String::new()));
 
@@ -189,11 +167,7 @@ fn build_update_form(
     // --- Visit details ---
     f.push((
         "homeVisitCnt".into(),
-        input.home_visit_cnt.clone().unwrap_or_else(|| {
-            current
-                .home_visit_cnt
-                .map_or(String::new(), |v| v.to_string())
-        }),
+        text(&input.home_visit_cnt, &current.home_visit_cnt),
     ));
 
     push_checkbox(
@@ -204,55 +178,50 @@ fn build_update_form(
 
     f.push((
         "visitAssignedToMemberId".into(),
-        input
-            .visit_assigned_to_member_id
-            .clone()
-            .unwrap_or_default(),
+        text(
+            &input.visit_assigned_to_member_id,
+            &current.visit_assigned_to_member_id,
+        ),
     ));
     f.push((
         "visitAssignedToMemberIdSecondary".into(),
-        input
-            .visit_assigned_to_member_id_secondary
-            .clone()
-            .unwrap_or_default(),
+        text(
+            &input.visit_assigned_to_member_id_secondary,
+            &current.visit_assigned_to_member_id_secondary,
+        ),
     ));
     f.push((
         "visitMileageInService".into(),
-        input.visit_mileage_in_service.clone().unwrap_or_else(|| {
-            current
-                .visit_mileage_hrs_in_svc
-                .map_or(String::new(), |v| v.to_string())
-        }),
+        text(
+            &input.visit_mileage_in_service,
+            &current.visit_mileage_in_service,
+        ),
     ));
     f.push((
         "visitHoursInService".into(),
-        input.visit_hours_in_service.clone().unwrap_or_default(),
+        text(
+            &input.visit_hours_in_service,
+            &current.visit_hours_in_service,
+        ),
     ));
     f.push((
         "visitScheduledDate".into(),
-        input
-            .visit_scheduled_date
-            .clone()
-            .unwrap_or_else(|| current.visit_scheduled_date.clone().unwrap_or_default()),
+        text(&input.visit_scheduled_date, &current.visit_scheduled_date),
     ));
     f.push((
         "visitScheduledTime".into(),
-        input.visit_scheduled_time.clone().unwrap_or_default(),
+        text(&input.visit_scheduled_time, &current.visit_scheduled_time),
     ));
     f.push((
         "peopleHelpedOverride".into(),
-        input.people_helped_override.clone().unwrap_or_else(|| {
-            current
-                .people_helped_override
-                .map_or(String::new(), |v| v.to_string())
-        }),
+        text(
+            &input.people_helped_override,
+            &current.people_helped_override,
+        ),
     ));
     f.push((
         "visitNotes".into(),
-        input
-            .visit_notes
-            .clone()
-            .unwrap_or_else(|| current.visit_notes.clone()),
+        text(&input.visit_notes, &current.visit_notes),
     ));
     f.push(("files".into(), String::new()));
 
@@ -273,17 +242,14 @@ fn build_update_form(
     );
     f.push((
         "referredFromOrganizationId".into(),
-        input
-            .referred_from_organization_id
-            .clone()
-            .unwrap_or_default(),
+        text(
+            &input.referred_from_organization_id,
+            &current.referred_from_organization_id,
+        ),
     ));
     f.push((
         "referralNote".into(),
-        input
-            .referral_note
-            .clone()
-            .unwrap_or_else(|| current.referral_note.clone()),
+        text(&input.referral_note, &current.referral_note),
     ));
 
     f
@@ -296,62 +262,34 @@ fn build_update_form(
 impl ServWare {
     /// Update an assistance request using read-modify-write.
     ///
-    /// 1. Fetches the current state of the request from the server
+    /// 1. Fetches the current form from the request detail page
     /// 2. Merges `input` fields over the current values
     /// 3. POSTs the complete form
-    pub async fn update_request(
-        &self,
-        request_id: u64,
-        input: &UpdateRequestInput,
-    ) -> anyhow::Result<()> {
+    ///
+    /// Fails with [`ServWareError::Validation`](super::ServWareError::Validation)
+    /// if ServWare shows the form again with error messages instead of
+    /// saving it.
+    pub async fn update_request(&self, request_id: u64, input: &UpdateRequestInput) -> Result<()> {
         tracing::debug!(request_id, "fetching current request state for update");
         let current = self
-            .get_request_by_id(request_id)
+            .fetch_request_detail(request_id)
             .await
             .context("failed to fetch current request state")?;
 
         let form = build_update_form(&current, input);
-        let url = Self::request_url(request_id);
+        let url = self.request_url(request_id);
 
         tracing::debug!(url, fields = form.len(), "posting request update");
 
         let response = self
-            .client
-            .post(&url)
-            .form(&form)
-            .send()
+            .send(|client| client.post(&url).form(&form))
             .await
             .context("update request POST failed")?;
 
-        let status = response.status();
-        tracing::debug!(%status, "update request response");
-
-        if !status.is_success() && !status.is_redirection() {
-            anyhow::bail!("update request failed with status {status}");
-        }
+        tracing::debug!(status = %response.status(), "update request response");
+        scrape::check_saved(response, "update request").await?;
 
         tracing::info!(request_id, "request updated successfully");
         Ok(())
     }
-
-    /// Fetch a single open assistance request by ID.
-    ///
-    /// Internally fetches open requests (large page size) and finds the matching one.
-    async fn get_request_by_id(&self, id: u64) -> anyhow::Result<AssistanceRequest> {
-        let params = FetchRequestsParams {
-            display_length: 1000,
-            ..FetchRequestsParams::new_open_asc()
-        };
-
-        let response = self
-            .fetch_requests(&params)
-            .await
-            .context("failed to fetch requests for get_request_by_id")?;
-
-        response
-            .aa_data
-            .into_iter()
-            .find(|r| r.id == id)
-            .with_context(|| format!("request {id} not found in fetched results"))
-    }
 }
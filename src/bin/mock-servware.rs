@@ -0,0 +1,64 @@
+//! Runs the in-memory ServWare stand-in on a local port with a few demo
+//! requests, so the CLI can be practiced against it via `--base-url`.
+
+use std::net::SocketAddr;
+
+use clap::Parser;
+use tracing_subscriber::EnvFilter;
+
+use svdp::api::fetch_lookups::LookupOption;
+use svdp::mock::MockRequest;
+use svdp::mock::MockServer;
+use svdp::mock::MockState;
+
+#[derive(Parser)]
+#[command(name = "mock-servware", about = "Local stand-in for ServWare")]
+pub struct Args {
+    #[arg(short, long, default_value = "127.0.0.1:8080")]
+    addr: SocketAddr,
+
+    #[arg(short, long, default_value = "demo")]
+    username: String,
+
+    #[arg(short, long, default_value = "demo")]
+    password: String,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_from_default_env().or_else(|_| EnvFilter::try_new("INFO"))?)
+        .init();
+
+    let args = Args::parse();
+    let mut state = MockState::new(&args.username, &args.password)
+        .with_member("44270", "Demo Volunteer")
+        .with_member("44271", "Second Volunteer")
+        .with_request(MockRequest {
+            household_count: 4,
+            ..MockRequest::open(3724739, 580815, "Jane", "Doe")
+        })
+        .with_request(MockRequest::open(3724740, 580816, "John", "Roe"))
+        .with_request(MockRequest {
+            status: "Completed".into(),
+            ..MockRequest::open(3724741, 580817, "Ann", "Poe")
+        });
+    state.assistance_types = vec![
+        LookupOption {
+            id: "16542".into(),
+            name: "Second Harvest Food".into(),
+        },
+        LookupOption {
+            id: "16522".into(),
+            name: "Gift Cards".into(),
+        },
+    ];
+
+    let server = MockServer::bind(args.addr, state).await?;
+    println!("mock ServWare at {}", server.base_url());
+    println!(
+        "try: cargo run -- --base-url {} get-requests",
+        server.base_url()
+    );
+    server.wait().await
+}
@@ -1,4 +1,6 @@
 pub mod api;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod nativity;
 
 use anyhow::Context;
@@ -12,6 +14,7 @@ pub struct Credentials {
 impl Credentials {
     pub const USER_ENV: &str = "SERVWARE_USER";
     pub const PASS_ENV: &str = "SERVWARE_PASS";
+    pub const SESSION_PASSPHRASE_ENV: &str = "SERVWARE_SESSION_PASSPHRASE";
 
     /// Interactively requests ServWare credentials from the user.
     pub fn prompt(use_env: bool) -> anyhow::Result<Credentials> {
@@ -33,6 +36,18 @@ impl Credentials {
             }
         };
 
+        let password = Self::prompt_password(false)?;
+
+        Ok(Credentials { username, password })
+    }
+
+    /// Interactively requests just the ServWare password, e.g. to renew a
+    /// saved session for a username that's already known.
+    pub fn prompt_password(use_env: bool) -> anyhow::Result<SecretString> {
+        if use_env {
+            let _ = dotenvy::dotenv();
+        }
+
         let password = match std::env::var(Self::PASS_ENV) {
             Ok(from_env) => SecretString::new(from_env.trim().to_string().into_boxed_str()),
             Err(_) => rpassword::prompt_password("ServWare password: ")
@@ -40,6 +55,22 @@ impl Credentials {
                 .into(),
         };
 
-        Ok(Credentials { username, password })
+        Ok(password)
+    }
+
+    /// Interactively requests the passphrase protecting a saved session file.
+    pub fn prompt_session_passphrase(use_env: bool) -> anyhow::Result<SecretString> {
+        if use_env {
+            let _ = dotenvy::dotenv();
+        }
+
+        let passphrase = match std::env::var(Self::SESSION_PASSPHRASE_ENV) {
+            Ok(from_env) => SecretString::new(from_env.trim().to_string().into_boxed_str()),
+            Err(_) => rpassword::prompt_password("Session file passphrase: ")
+                .context("failed to read session passphrase")?
+                .into(),
+        };
+
+        Ok(passphrase)
     }
 }
@@ -6,7 +6,10 @@ use clap::Subcommand;
 use svdp::Credentials;
 use tracing_subscriber::EnvFilter;
 
+use svdp::api;
 use svdp::api::ServWare;
+use svdp::api::fetch_calendar::CalendarFeed;
+use svdp::api::session_file::SessionFile;
 use svdp::nativity;
 
 #[derive(Parser)]
@@ -17,6 +20,22 @@ pub struct Args {
 
     #[arg(short = 'e', long, default_value_t = false)]
     search_env: bool,
+
+    /// ServWare origin to send requests to. Override to target a local stand-in server.
+    #[arg(long, env = api::BASE_URL_ENV, default_value = api::DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// Keep the ServWare session alive in the background for long-running commands.
+    #[arg(long, default_value_t = false)]
+    keep_alive: bool,
+
+    /// Save the login session to this file and reuse it on later runs instead of logging in.
+    #[arg(long)]
+    session_file: Option<PathBuf>,
+
+    /// Encrypt the session file with a passphrase.
+    #[arg(long, default_value_t = false, requires = "session_file")]
+    encrypt_session: bool,
 }
 
 #[derive(Subcommand)]
@@ -31,6 +50,10 @@ pub enum Command {
     GetRequests {
         #[arg(short, long, default_value = "requests.csv")]
         csv: PathBuf,
+
+        /// Add each request's approval status (one extra call per request).
+        #[arg(short, long, default_value_t = false)]
+        approvals: bool,
     },
 
     /// Marks all requests in a CSV as complete with volunteer and visit details.
@@ -46,6 +69,116 @@ pub enum Command {
     AddAssistance {
         #[arg(short, long, default_value = "requests.csv")]
         csv: PathBuf,
+
+        /// Use ServWare's configured Second Harvest value instead of $70 for
+        /// rows without `second_harvest_dollars` (experimental).
+        #[arg(long, default_value_t = false)]
+        server_value: bool,
+    },
+
+    /// Opens requests from an intake CSV, adding new neighbors where needed.
+    Intake {
+        #[arg(short, long, default_value = "intake.csv")]
+        csv: PathBuf,
+    },
+
+    /// Applies phone, email and address corrections from a CSV to neighbor records.
+    UpdateContacts {
+        #[arg(short, long, default_value = "contacts.csv")]
+        csv: PathBuf,
+    },
+
+    /// Writes assistance types, denial reasons and referral organizations (ID and name) to CSVs.
+    ListLookups {
+        #[arg(short, long, default_value = "lookups")]
+        dir: PathBuf,
+    },
+
+    /// Searches neighbors by name, phone number or address.
+    FindClient {
+        /// e.g. a last name, "650-555-0100" or "1 Main St".
+        query: String,
+    },
+
+    /// Prints open neighbor follow-ups, soonest first.
+    FollowUps {
+        /// Only those due within this many days (overdue ones included).
+        #[arg(short, long)]
+        days: Option<u32>,
+    },
+
+    /// Adds a follow-up to a neighbor, e.g. "call back in 2 weeks".
+    AddFollowUp {
+        #[arg(short, long)]
+        neighbor_id: u64,
+
+        /// Days from today until it's due.
+        #[arg(short, long, default_value_t = 14)]
+        days: u32,
+
+        #[arg(long)]
+        notes: String,
+    },
+
+    /// Logs volunteer hours and mileage not tied to a request from a CSV.
+    LogHours {
+        #[arg(short, long, default_value = "hours.csv")]
+        csv: PathBuf,
+    },
+
+    /// Writes pending assistance items to a CSV, or marks the rows with a check number paid.
+    Pending {
+        #[arg(short, long, default_value = "pending.csv")]
+        csv: PathBuf,
+
+        /// Read the CSV back and mark each row with a check number paid.
+        #[arg(long, default_value_t = false)]
+        mark_paid: bool,
+    },
+
+    /// Prints the fiscal-year dashboard statistics, or writes them to a CSV or JSON file.
+    Stats {
+        #[arg(short, long, conflicts_with = "json")]
+        csv: Option<PathBuf>,
+
+        #[arg(short, long)]
+        json: Option<PathBuf>,
+    },
+
+    /// Prints the conference activity report for a date range, or writes it to a CSV or JSON file
+    /// (experimental: check the figures against ServWare before filing them).
+    Report {
+        /// First day of the report, e.g. 2026-01-01.
+        #[arg(long)]
+        from: chrono::NaiveDate,
+
+        /// Last day of the report, inclusive.
+        #[arg(long)]
+        to: chrono::NaiveDate,
+
+        #[arg(short, long, conflicts_with = "json")]
+        csv: Option<PathBuf>,
+
+        #[arg(short, long)]
+        json: Option<PathBuf>,
+    },
+
+    /// Writes upcoming home visits to an iCalendar (.ics) file for phone calendars.
+    Calendar {
+        #[arg(short, long, default_value = "servware.ics")]
+        ics: PathBuf,
+
+        /// How many days ahead to include.
+        #[arg(short, long, default_value_t = 30)]
+        days: u32,
+
+        /// Also include follow-ups.
+        #[arg(long, default_value_t = false)]
+        follow_ups: bool,
+
+        /// Also include conference events.
+        #[arg(long, default_value_t = false)]
+        conference_events: bool,
     },
 }
 
@@ -56,21 +189,153 @@ async fn main() -> anyhow::Result<()> {
         .init();
 
     let args = Args::parse();
+    let session_file = match &args.session_file {
+        Some(path) => {
+            let passphrase = if args.encrypt_session {
+                Some(Credentials::prompt_session_passphrase(args.search_env)?)
+            } else {
+                None
+            };
+            Some(SessionFile::new(path, passphrase))
+        }
+        None => None,
+    };
+
+    let client = connect(&args, session_file.as_ref()).await?;
+    if args.keep_alive {
+        client.start_keep_alive(api::KEEP_ALIVE_INTERVAL)?;
+    }
+
+    let result = run(&client, args.command).await;
+    match &session_file {
+        // Leave the session open for the next run, re-saving in case it was
+        // renewed along the way.
+        Some(file) => {
+            if let Err(e) = client.save_session(file) {
+                tracing::warn!("failed to save session: {e:#}");
+            }
+        }
+        // Log out even when the command fails so shared machines don't keep
+        // a live session around.
+        None => {
+            if let Err(e) = client.logout().await {
+                tracing::warn!("failed to log out: {e:#}");
+            }
+        }
+    }
+    result
+}
+
+/// Resume the saved session if there is a live one, otherwise log in.
+async fn connect(args: &Args, session_file: Option<&SessionFile>) -> anyhow::Result<ServWare> {
+    if let Some(file) = session_file
+        && file.is_locked()
+    {
+        anyhow::bail!(
+            "session file {} is encrypted; rerun with --encrypt-session",
+            file.path().display()
+        );
+    }
+
+    if let Some(file) = session_file
+        && let Some(client) = ServWare::resume_session(&args.base_url, file).await?
+    {
+        // If the session expires partway through a batch, ask for the
+        // password and carry on rather than stopping with half the rows
+        // applied.
+        let search_env = args.search_env;
+        return Ok(client.with_password_prompt(move || Credentials::prompt_password(search_env)));
+    }
+
     let credentials = Credentials::prompt(args.search_env).context("failed to get credentials")?;
-    let client = ServWare::new_session(&credentials.username, &credentials.password).await?;
+    let client =
+        ServWare::new_session(&args.base_url, &credentials.username, &credentials.password).await?;
 
-    match args.command {
-        Command::GetRequests { csv } => {
-            nativity::requests_to_csv(&client, &csv).await?;
+    if let Some(file) = session_file {
+        client
+            .save_session(file)
+            .context("failed to save session")?;
+    }
+    Ok(client)
+}
+
+async fn run(client: &ServWare, command: Command) -> anyhow::Result<()> {
+    match command {
+        Command::GetRequests { csv, approvals } => {
+            nativity::requests_to_csv(client, &csv, approvals).await?;
         }
         Command::ListMembers { csv } => {
-            nativity::members_to_csv(&client, &csv).await?;
+            nativity::members_to_csv(client, &csv).await?;
         }
         Command::MarkComplete { csv, volunteer_id } => {
-            nativity::update_complete(&client, &csv, &volunteer_id).await?;
+            nativity::update_complete(client, &csv, &volunteer_id).await?;
+        }
+        Command::AddAssistance { csv, server_value } => {
+            nativity::add_assistance(client, &csv, server_value).await?;
+        }
+        Command::Intake { csv } => {
+            nativity::intake(client, &csv).await?;
+        }
+        Command::UpdateContacts { csv } => {
+            nativity::update_contacts(client, &csv).await?;
+        }
+        Command::ListLookups { dir } => {
+            nativity::lookups_to_csv(client, &dir).await?;
+        }
+        Command::FindClient { query } => {
+            nativity::print_clients(client, &query).await?;
+        }
+        Command::FollowUps { days } => {
+            nativity::print_follow_ups(client, days).await?;
+        }
+        Command::AddFollowUp {
+            neighbor_id,
+            days,
+            notes,
+        } => {
+            nativity::add_follow_up(client, neighbor_id, days, &notes).await?;
+        }
+        Command::LogHours { csv } => {
+            nativity::log_hours(client, &csv).await?;
+        }
+        Command::Pending { csv, mark_paid } => {
+            if mark_paid {
+                nativity::mark_paid(client, &csv).await?;
+            } else {
+                nativity::pending_to_csv(client, &csv).await?;
+            }
         }
-        Command::AddAssistance { csv } => {
-            nativity::add_assistance(&client, &csv).await?;
+        Command::Stats { csv, json } => match (csv, json) {
+            (Some(csv), _) => nativity::statistics_to_csv(client, &csv).await?,
+            (None, Some(json)) => nativity::statistics_to_json(client, &json).await?,
+            (None, None) => nativity::print_statistics(client).await?,
+        },
+        Command::Report {
+            from,
+            to,
+            csv,
+            json,
+        } => match (csv, json) {
+            (Some(csv), _) => nativity::activity_report_to_csv(client, from, to, &csv).await?,
+            (None, Some(json)) => {
+                nativity::activity_report_to_json(client, from, to, &json).await?
+            }
+            (None, None) => nativity::print_activity_report(client, from, to).await?,
+        },
+        Command::Calendar {
+            ics,
+            days,
+            follow_ups,
+            conference_events,
+        } => {
+            let mut feeds = vec![CalendarFeed::HomeVisits];
+            if follow_ups {
+                feeds.push(CalendarFeed::FollowUps);
+            }
+            if conference_events {
+                feeds.push(CalendarFeed::ConferenceEvents);
+            }
+            nativity::calendar_to_ics(client, &ics, &feeds, days).await?;
         }
     }
 
@@ -0,0 +1,1954 @@
+//! # Mock
+//!
+//! An in-memory stand-in for the slice of ServWare this crate talks to.
+//! Start a [`MockServer`], point [`ServWare`](crate::api::ServWare) at its
+//! [`base_url`](MockServer::base_url), and the nativity workflows run end to
+//! end without live credentials or production data.
+//!
+//! The fake only imitates what `api/` actually relies on:
+//! - login redirects (`/app/home` on success, back to `/security/login` on
+//!   failure, `/security/redirectLogin` when a session is missing)
+//! - the DataTables `/app/assistancerequests/list`, `/app/clients/list` and
+//!   `/app/clients/followups/list` JSON, with each request's neighbor
+//!   carrying their follow-ups
+//! - the `/app/home/statistics` JSON, counted from the seeded requests
+//! - the `/app/calendar/*` feeds, with home visits taken from each request's
+//!   scheduled visit date
+//! - the request and client detail HTML with their full edit forms
+//! - the request update POST (urlencoded, or multipart with a file), the
+//!   client update POST and the assistance item POST
+//! - the new client, new request and new follow-up POSTs
+//! - validation failures: a client without a last name, a request without
+//!   a date, a denial without a reason, or an assistance item without a
+//!   number or date, gets its form back with Spring's
+//!   `<form:errors>` messages and a 200 instead of a redirect
+//! - the attachment list fragment and file downloads
+//! - the approval list fragment, from [`MockState::approvals`]
+//! - the `itemvalue` lookup, answered from [`MockState::item_values`]
+//! - session keep-alive and logout
+//!
+//! Every POST is recorded so a test can assert exactly what was sent.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+
+use anyhow::Context;
+use axum::Form;
+use axum::Json;
+use axum::Router;
+use axum::extract::FromRequest;
+use axum::extract::Multipart;
+use axum::extract::Path;
+use axum::extract::Query;
+use axum::extract::Request;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::http::StatusCode;
+use axum::http::header;
+use axum::response::Html;
+use axum::response::IntoResponse;
+use axum::response::Redirect;
+use axum::response::Response;
+use axum::routing::get;
+use axum::routing::post;
+use serde_json::json;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+use crate::api::fetch_approvals::Approval;
+use crate::api::fetch_lookups::LookupOption;
+use crate::api::fetch_members::Member;
+
+const SESSION_COOKIE: &str = "JSESSIONID";
+
+/// Request edit-form controls, by kind, as the detail page renders them.
+const MEMBER_SELECTS: [&str; 3] = [
+    "requestAssignedToMemberId",
+    "visitAssignedToMemberId",
+    "visitAssignedToMemberIdSecondary",
+];
+const TEXT_INPUTS: [&str; 10] = [
+    "denialReasonStr",
+    "clientFirstName",
+    "clientLastName",
+    "dateRequested",
+    "homeVisitCnt",
+    "visitMileageInService",
+    "visitHoursInService",
+    "visitScheduledDate",
+    "visitScheduledTime",
+    "peopleHelpedOverride",
+];
+const TEXTAREAS: [&str; 3] = ["requestNote", "visitNotes", "referralNote"];
+/// Client edit-form controls, named after the client JSON fields they hold.
+/// `householdIncome` stands in for the fields the crate doesn't model.
+const CLIENT_TEXT_INPUTS: [&str; 14] = [
+    "firstName",
+    "lastName",
+    "middleInitial",
+    "birthDate",
+    "homePhone",
+    "workPhone",
+    "mobilePhone",
+    "emailAddress",
+    "streetAddressLine1",
+    "streetAddressLine2",
+    "city",
+    "stateCode",
+    "postalCode",
+    "householdIncome",
+];
+const CLIENT_TEXTAREAS: [&str; 2] = ["notes", "alertNote"];
+const CLIENT_CHECKBOXES: [&str; 6] = [
+    "textCommunicationPreferred",
+    "parishioner",
+    "homeless",
+    "disabledClient",
+    "veteran",
+    "privateClient",
+];
+const CHECKBOXES: [&str; 10] = [
+    "homeVisitRequired",
+    "otherVisit",
+    "elderCareVisit",
+    "hospitalVisit",
+    "prisonVisit",
+    "telephoneVisit",
+    "churchPantryVisit",
+    "visitCompleted",
+    "referredToAgency",
+    "referredToConference",
+];
+
+// ---------------------------------------------------------------------------
+// State
+// ---------------------------------------------------------------------------
+
+/// A form POST received by the mock, in the order the fields were sent.
+#[derive(Debug, Clone)]
+pub struct RecordedForm {
+    /// The request, the neighbor for client forms, or the assistance item
+    /// for item edits the form was posted to.
+    pub id: u64,
+    pub fields: Vec<(String, String)>,
+}
+
+impl RecordedForm {
+    /// First value sent for `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// A file uploaded to a request through the request form.
+#[derive(Debug, Clone)]
+pub struct MockFile {
+    pub id: u64,
+    pub request_id: u64,
+    pub name: String,
+    pub content_type: String,
+    pub contents: Vec<u8>,
+}
+
+/// Seed data for one assistance request held by the mock.
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    pub id: u64,
+    pub status: String,
+    pub date_requested: String,
+    pub client_id: u64,
+    pub first_name: String,
+    pub last_name: String,
+    pub street_address: String,
+    pub city: String,
+    pub household_count: u32,
+}
+
+impl MockRequest {
+    /// An open request with placeholder address and household details.
+    pub fn open(id: u64, client_id: u64, first_name: &str, last_name: &str) -> Self {
+        Self {
+            id,
+            status: "Open".into(),
+            date_requested: "02/02/2026".into(),
+            client_id,
+            first_name: first_name.into(),
+            last_name: last_name.into(),
+            street_address: "1 Main St".into(),
+            city: "Menlo Park".into(),
+            household_count: 1,
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "id": self.id,
+            "version": 0,
+            "markedForDeletion": false,
+            "dateCreated": format!("{} 09:00 AM", self.date_requested),
+            "status": self.status,
+            "dateRequested": self.date_requested,
+            "requestNote": "",
+            "streetAddressLine1": self.street_address,
+            "streetAddressLine2": "",
+            "city": self.city,
+            "stateCode": "CA",
+            "postalCode": "94025",
+            "requestAssignedToMember": null,
+            "calculatedHouseholdCount": self.household_count,
+            "homeVisitRequired": false,
+            "visitCompleted": false,
+            "visitNotes": "",
+            "referralNote": "",
+            "assistanceItems": [],
+            "client": {
+                "id": self.client_id,
+                "firstName": self.first_name,
+                "lastName": self.last_name,
+                "streetAddressLine1": self.street_address,
+                "city": self.city,
+                "stateCode": "CA",
+                "lastRequestDate": self.date_requested,
+            },
+        })
+    }
+}
+
+/// Seed data for one neighbor held by the mock.
+#[derive(Debug, Clone)]
+pub struct MockClient {
+    pub id: u64,
+    pub first_name: String,
+    pub last_name: String,
+    pub street_address: String,
+    pub city: String,
+    pub home_phone: String,
+    pub mobile_phone: String,
+}
+
+impl MockClient {
+    /// A neighbor with placeholder address and no phone numbers.
+    pub fn new(id: u64, first_name: &str, last_name: &str) -> Self {
+        Self {
+            id,
+            first_name: first_name.into(),
+            last_name: last_name.into(),
+            street_address: "1 Main St".into(),
+            city: "Menlo Park".into(),
+            home_phone: String::new(),
+            mobile_phone: String::new(),
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "id": self.id,
+            "firstName": self.first_name,
+            "lastName": self.last_name,
+            "streetAddressLine1": self.street_address,
+            "city": self.city,
+            "stateCode": "CA",
+            "postalCode": "94025",
+            "homePhone": self.home_phone,
+            "mobilePhone": self.mobile_phone,
+            "lastRequestDate": null,
+        })
+    }
+}
+
+/// Everything the mock knows. Tests seed it before starting the server and
+/// inspect it afterwards through [`MockServer::state`].
+#[derive(Debug, Default)]
+pub struct MockState {
+    pub username: String,
+    pub password: String,
+    pub members: Vec<Member>,
+    /// Requests as the list API would return them, keyed by request ID.
+    pub requests: BTreeMap<u64, serde_json::Value>,
+    /// Neighbors as the client list would return them, keyed by client ID.
+    /// Every seeded request adds its neighbor here.
+    pub clients: BTreeMap<u64, serde_json::Value>,
+    /// Every `POST /app/assistancerequests/{id}` received.
+    pub request_updates: Vec<RecordedForm>,
+    /// Files uploaded through the request form, listed and served back by
+    /// `/app/assistancerequests/{id}/files/*`.
+    pub files: Vec<MockFile>,
+    /// Every `POST /app/clients/{id}` received.
+    pub client_updates: Vec<RecordedForm>,
+    /// Every `POST /app/clients/new` received, under the ID it was given.
+    pub created_clients: Vec<RecordedForm>,
+    /// Every `POST /app/assistancerequests/new` received, under the ID it
+    /// was given.
+    pub created_requests: Vec<RecordedForm>,
+    /// Follow-ups as the follow-up list would return them, keyed by ID.
+    pub follow_ups: BTreeMap<u64, serde_json::Value>,
+    /// Every `POST /app/clients/{id}/followups/new` received, under the
+    /// neighbor's ID.
+    pub created_follow_ups: Vec<RecordedForm>,
+    /// Mileage and hours entries as their list would return them, keyed by ID.
+    pub service_hours: BTreeMap<u64, serde_json::Value>,
+    /// Every `POST /app/mileagehoursinservicelist/new` received, under the
+    /// ID it was given.
+    pub created_service_hours: Vec<RecordedForm>,
+    /// Every `POST /app/assistancerequests/{id}/assistanceitems/new` received.
+    pub assistance_items: Vec<RecordedForm>,
+    /// Every `POST /app/assistancerequests/{id}/assistanceitems/{item_id}`
+    /// received, under the item's ID.
+    pub item_updates: Vec<RecordedForm>,
+    /// Every client or request POST (new or update), new assistance item,
+    /// follow-up or mileage and hours POST turned away with validation
+    /// errors, under the ID it was posted for (the client's for a
+    /// follow-up, 0 for a new client, request or hours entry). None of them
+    /// were saved.
+    pub rejected_forms: Vec<RecordedForm>,
+    /// Number of successful logins, including re-logins.
+    pub logins: u32,
+    /// Number of sessions ended through `/security/logout`.
+    pub logouts: u32,
+    /// Number of `/security/extendSession` calls made with a live session.
+    pub session_extensions: u32,
+    /// Options of the new assistance item form's `assistanceTypeId` select.
+    pub assistance_types: Vec<LookupOption>,
+    /// Options of the request form's `denialReasonId` select.
+    pub denial_reasons: Vec<LookupOption>,
+    /// Options of the request form's `referredFromOrganizationId` select.
+    pub referral_organizations: Vec<LookupOption>,
+    /// Default values by assistance type ID, for every client.
+    pub item_values: HashMap<String, f64>,
+    /// Events served as-is by `/app/calendar/conferenceevents`.
+    pub conference_events: Vec<serde_json::Value>,
+    /// Approval chains by request ID, served by
+    /// `/app/assistancerequests/{id}/approval/list`.
+    pub approvals: HashMap<u64, Vec<Approval>>,
+
+    sessions: HashSet<String>,
+    next_item_id: u64,
+    /// Edit-form fields the list JSON has no room for (member IDs, denial
+    /// reason, referral organization, ...), as last posted.
+    request_forms: HashMap<u64, HashMap<String, String>>,
+}
+
+impl MockState {
+    pub fn new(username: &str, password: &str) -> Self {
+        Self {
+            username: username.into(),
+            password: password.into(),
+            next_item_id: 1,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_member(mut self, id: &str, name: &str) -> Self {
+        self.members.push(Member {
+            id: id.into(),
+            name: name.into(),
+        });
+        self
+    }
+
+    pub fn with_request(mut self, request: MockRequest) -> Self {
+        let json = request.to_json();
+        self.clients
+            .entry(request.client_id)
+            .or_insert_with(|| json["client"].clone());
+        self.requests.insert(request.id, json);
+        self
+    }
+
+    /// An open follow-up on a neighbor, due `follow_up_date` (`MM/DD/YYYY`).
+    pub fn with_follow_up(mut self, client_id: u64, follow_up_date: &str, notes: &str) -> Self {
+        let id = self.follow_ups.keys().max().map_or(1, |max| max + 1);
+        self.follow_ups.insert(
+            id,
+            self::follow_up_json(id, client_id, follow_up_date, notes),
+        );
+        self
+    }
+
+    /// Hours and miles a member logged on `date_of_service` (`MM/DD/YYYY`).
+    pub fn with_service_hours(
+        mut self,
+        member_id: &str,
+        date_of_service: &str,
+        hours: f64,
+        miles: f64,
+    ) -> Self {
+        let id = self.service_hours.keys().max().map_or(1, |max| max + 1);
+        let entry = self::service_hours_json(&self, id, member_id, date_of_service, hours, miles);
+        self.service_hours.insert(id, entry);
+        self
+    }
+
+    /// A neighbor with no request, e.g. a walk-in.
+    pub fn with_client(mut self, client: MockClient) -> Self {
+        self.clients.insert(client.id, client.to_json());
+        self
+    }
+
+    /// Status of a request, e.g. `"Completed"` after `mark-complete`.
+    pub fn request_status(&self, id: u64) -> Option<&str> {
+        self.requests.get(&id)?.get("status")?.as_str()
+    }
+
+    /// An assistance item of a request, as stored in its `assistanceItems`.
+    pub fn assistance_item(&self, id: u64, item_id: u64) -> Option<&serde_json::Value> {
+        self.requests.get(&id)?["assistanceItems"]
+            .as_array()?
+            .iter()
+            .find(|item| item["id"] == item_id)
+    }
+
+    fn assistance_item_mut(&mut self, id: u64, item_id: u64) -> Option<&mut serde_json::Value> {
+        self.requests.get_mut(&id)?["assistanceItems"]
+            .as_array_mut()?
+            .iter_mut()
+            .find(|item| item["id"] == item_id)
+    }
+
+    /// The request's edit form as the detail page renders it: values derived
+    /// from the list JSON, overlaid with whatever was last posted.
+    fn request_form(&self, id: u64) -> Option<HashMap<String, String>> {
+        let request = self.requests.get(&id)?;
+        let text = |value: &serde_json::Value| value.as_str().unwrap_or_default().to_string();
+        let checked = |value: &serde_json::Value| {
+            if value.as_bool() == Some(true) {
+                "true".to_string()
+            } else {
+                String::new()
+            }
+        };
+
+        let mut form: HashMap<String, String> = [
+            ("status", text(&request["status"])),
+            ("clientFirstName", text(&request["client"]["firstName"])),
+            ("clientLastName", text(&request["client"]["lastName"])),
+            ("dateRequested", text(&request["dateRequested"])),
+            ("requestNote", text(&request["requestNote"])),
+            ("homeVisitRequired", checked(&request["homeVisitRequired"])),
+            ("visitCompleted", checked(&request["visitCompleted"])),
+            ("visitScheduledDate", text(&request["visitScheduledDate"])),
+            ("visitNotes", text(&request["visitNotes"])),
+            ("referralNote", text(&request["referralNote"])),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value))
+        .collect();
+
+        if let Some(posted) = self.request_forms.get(&id) {
+            form.extend(posted.clone());
+        }
+        Some(form)
+    }
+
+    /// Drop every live session, as if they all timed out on the server.
+    pub fn expire_sessions(&mut self) {
+        self.sessions.clear();
+    }
+}
+
+type Shared = Arc<Mutex<MockState>>;
+
+fn lock(state: &Shared) -> MutexGuard<'_, MockState> {
+    state
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// ---------------------------------------------------------------------------
+// Server
+// ---------------------------------------------------------------------------
+
+/// A running mock ServWare. The server task stops when this is dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Shared,
+    task: JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Serve `state` on an ephemeral localhost port.
+    pub async fn start(state: MockState) -> anyhow::Result<Self> {
+        Self::bind(SocketAddr::from(([127, 0, 0, 1], 0)), state).await
+    }
+
+    /// Serve `state` on the given address.
+    pub async fn bind(addr: SocketAddr, state: MockState) -> anyhow::Result<Self> {
+        let listener = TcpListener::bind(addr)
+            .await
+            .with_context(|| format!("failed to bind mock server to {addr}"))?;
+        let addr = listener.local_addr()?;
+        let state: Shared = Arc::new(Mutex::new(state));
+
+        let app = self::router(state.clone());
+        let task = tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, app).await {
+                tracing::error!("mock server stopped: {e}");
+            }
+        });
+
+        tracing::info!(%addr, "mock ServWare listening");
+        Ok(Self { addr, state, task })
+    }
+
+    /// Origin to pass as the client's base URL.
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Inspect or modify the server's state.
+    pub fn state(&self) -> MutexGuard<'_, MockState> {
+        lock(&self.state)
+    }
+
+    /// Wait until the server task exits.
+    pub async fn wait(mut self) -> anyhow::Result<()> {
+        (&mut self.task).await.context("mock server task failed")
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn router(state: Shared) -> Router {
+    Router::new()
+        .route("/security/login", get(login_page).post(login))
+        .route("/security/logout", post(logout))
+        .route("/security/redirectLogin", get(redirect_login))
+        .route("/security/extendSession", get(extend_session))
+        .route("/app/home", get(home))
+        .route("/app/home/statistics", get(statistics))
+        .route("/app/calendar/{feed}", get(calendar))
+        .route("/app/reports", post(activity_report))
+        .route("/app/clients/list", get(list_clients))
+        .route("/app/clients/new", post(create_client))
+        .route("/app/clients/followups/list", get(list_follow_ups))
+        .route("/app/clients/{id}/followups/new", post(create_follow_up))
+        .route("/app/clients/{id}", get(client_detail).post(update_client))
+        .route("/app/mileagehoursinservicelist", get(service_hours_page))
+        .route(
+            "/app/mileagehoursinservicelist/list",
+            get(list_service_hours),
+        )
+        .route(
+            "/app/mileagehoursinservicelist/new",
+            post(create_service_hours),
+        )
+        .route("/app/assistancerequests/list", get(list_requests))
+        .route("/app/assistancerequests/itemvalue", get(item_value))
+        .route("/app/assistancerequests/new", post(create_request))
+        .route(
+            "/app/assistancerequests/pendingassistance",
+            get(pending_assistance),
+        )
+        .route(
+            "/app/assistancerequests/{id}",
+            get(request_detail).post(update_request),
+        )
+        .route("/app/assistancerequests/{id}/files/list", get(list_files))
+        .route(
+            "/app/assistancerequests/{id}/approval/list",
+            get(list_approvals),
+        )
+        .route(
+            "/app/assistancerequests/{id}/files/{file_id}",
+            get(download_file),
+        )
+        .route(
+            "/app/assistancerequests/{id}/assistanceitems/new",
+            get(assistance_item_form).post(add_assistance_item),
+        )
+        .route(
+            "/app/assistancerequests/{id}/assistanceitems/{item_id}",
+            get(assistance_item).post(update_assistance_item),
+        )
+        .with_state(state)
+}
+
+// ---------------------------------------------------------------------------
+// Sessions
+// ---------------------------------------------------------------------------
+
+fn session_id(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(name, _)| *name == SESSION_COOKIE)
+        .map(|(_, value)| value.to_string())
+}
+
+fn has_session(state: &MockState, headers: &HeaderMap) -> bool {
+    self::session_id(headers).is_some_and(|id| state.sessions.contains(&id))
+}
+
+/// How ServWare bounces a request that has no live session.
+fn session_timeout() -> Response {
+    Redirect::to("/security/redirectLogin").into_response()
+}
+
+async fn login_page() -> Html<&'static str> {
+    Html(r#"<html><body><form method="post" action="/security/login"></form></body></html>"#)
+}
+
+async fn login(State(state): State<Shared>, Form(form): Form<HashMap<String, String>>) -> Response {
+    let mut state = lock(&state);
+    let username = form.get("username").map(String::as_str).unwrap_or_default();
+    let password = form.get("password").map(String::as_str).unwrap_or_default();
+
+    if username != state.username || password != state.password {
+        return Redirect::to("/security/login?error").into_response();
+    }
+
+    state.logins += 1;
+    let session = format!("mock-session-{}", state.logins);
+    state.sessions.insert(session.clone());
+
+    (
+        [(
+            header::SET_COOKIE,
+            format!("{SESSION_COOKIE}={session}; Path=/; HttpOnly"),
+        )],
+        Redirect::to("/app/home?continue"),
+    )
+        .into_response()
+}
+
+async fn logout(State(state): State<Shared>, headers: HeaderMap) -> Redirect {
+    let mut state = lock(&state);
+    if let Some(id) = self::session_id(&headers)
+        && state.sessions.remove(&id)
+    {
+        state.logouts += 1;
+    }
+    Redirect::to("/security/login?logout")
+}
+
+async fn redirect_login() -> Redirect {
+    Redirect::to("/security/login")
+}
+
+async fn extend_session(State(state): State<Shared>, headers: HeaderMap) -> Response {
+    let mut state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+    state.session_extensions += 1;
+    StatusCode::OK.into_response()
+}
+
+async fn home(State(state): State<Shared>, headers: HeaderMap) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+    Html("<html><body><h1>Home</h1></body></html>").into_response()
+}
+
+async fn statistics(State(state): State<Shared>, headers: HeaderMap) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    let with_status = |status: &str| {
+        state
+            .requests
+            .values()
+            .filter(|r| r["status"] == status)
+            .count()
+    };
+    let neighbors: HashSet<u64> = state
+        .requests
+        .values()
+        .filter_map(|r| r["client"]["id"].as_u64())
+        .collect();
+
+    Json(json!([
+        { "count": with_status("Completed"), "countDescription": "Completed Requests (FY)" },
+        { "count": with_status("Open"), "countDescription": "Open Requests (FY)" },
+        { "count": neighbors.len(), "countDescription": "Total Neighbors" },
+    ]))
+    .into_response()
+}
+
+async fn calendar(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Path(feed): Path<String>,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    let bound = |name: &str| query.get(name).and_then(|v| v.parse::<i64>().ok());
+    let (Some(start), Some(end)) = (bound("start"), bound("end")) else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+
+    let events: Vec<serde_json::Value> = match feed.as_str() {
+        "homevisits" => state
+            .requests
+            .values()
+            .filter_map(|r| {
+                let date = r["visitScheduledDate"].as_str()?;
+                let date = chrono::NaiveDate::parse_from_str(date, "%m/%d/%Y").ok()?;
+                let visit = date
+                    .and_hms_opt(0, 0, 0)?
+                    .and_local_timezone(self::pacific())
+                    .single()?;
+                if !(start..=end).contains(&visit.timestamp()) {
+                    return None;
+                }
+                let client = &r["client"];
+                Some(json!({
+                    "title": format!(
+                        "Home Visit: {}, {}",
+                        client["lastName"].as_str().unwrap_or_default(),
+                        client["firstName"].as_str().unwrap_or_default()
+                    ),
+                    "start": visit.format("%Y-%m-%dT%H:%M:%S%.3f%z").to_string(),
+                    "end": null,
+                    "url": format!("/app/assistancerequests/{}", r["id"]),
+                    "eventType": null,
+                    "colorCode": null,
+                    "textColorCode": null,
+                    "allDay": false,
+                    "editable": false,
+                }))
+            })
+            .collect(),
+        "conferenceevents" => state.conference_events.clone(),
+        "followups" => Vec::new(),
+        _ => return StatusCode::NOT_FOUND.into_response(),
+    };
+    Json(events).into_response()
+}
+
+/// The conference activity report: requests dated within the range, and
+/// assistance provided within it by type.
+async fn activity_report(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Form(form): Form<HashMap<String, String>>,
+) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    let date = |value: &str| chrono::NaiveDate::parse_from_str(value, "%m/%d/%Y").ok();
+    let param = |name: &str| form.get(name).map(String::as_str).unwrap_or_default();
+    let (Some(from), Some(to)) = (date(param("startDate")), date(param("endDate"))) else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+    let in_range = |value: &serde_json::Value| {
+        date(value.as_str().unwrap_or_default()).is_some_and(|d| from <= d && d <= to)
+    };
+
+    let requests: Vec<&serde_json::Value> = state
+        .requests
+        .values()
+        .filter(|r| in_range(&r["dateRequested"]))
+        .collect();
+    let with_status = |status: &str| requests.iter().filter(|r| r["status"] == status).count();
+    let neighbors: HashSet<u64> = requests
+        .iter()
+        .filter_map(|r| r["client"]["id"].as_u64())
+        .collect();
+    let people: u64 = requests
+        .iter()
+        .filter_map(|r| r["calculatedHouseholdCount"].as_u64())
+        .sum();
+
+    let mut assistance: BTreeMap<String, (u64, f64)> = BTreeMap::new();
+    for item in state
+        .requests
+        .values()
+        .filter_map(|r| r["assistanceItems"].as_array())
+        .flatten()
+        .filter(|item| in_range(&item["dateProvided"]))
+    {
+        let type_id = item["assistanceType"]["id"].to_string();
+        let name = state
+            .assistance_types
+            .iter()
+            .find(|t| t.id == type_id)
+            .map_or_else(|| format!("Type {type_id}"), |t| t.name.clone());
+        let entry = assistance.entry(name).or_default();
+        entry.0 += 1;
+        entry.1 += item["monetaryValue"].as_f64().unwrap_or_default();
+    }
+
+    let line = |description: &str, count: String, amount: String| {
+        format!(
+            "<tr><td>{}</td><td>{count}</td><td>{amount}</td></tr>",
+            self::escape(description)
+        )
+    };
+    let mut rows = String::from(r#"<tr><th colspan="3">Requests</th></tr>"#);
+    for status in ["Completed", "Open", "Denied"] {
+        rows.push_str(&line(
+            &format!("{status} Requests"),
+            with_status(status).to_string(),
+            String::new(),
+        ));
+    }
+    rows.push_str(&line(
+        "Neighbors Served",
+        neighbors.len().to_string(),
+        String::new(),
+    ));
+    rows.push_str(&line("People Helped", people.to_string(), String::new()));
+    rows.push_str(r#"<tr><th colspan="3">Assistance</th></tr>"#);
+    for (name, (count, amount)) in &assistance {
+        rows.push_str(&line(name, count.to_string(), format!("${amount:.2}")));
+    }
+
+    Html(format!(
+        r#"<html><body><h1>Conference Activity Report</h1><table class="table"><thead><tr><th>Description</th><th>Count</th><th>Amount</th></tr></thead><tbody>{rows}</tbody></table></body></html>"#
+    ))
+    .into_response()
+}
+
+/// Time zone the mock renders calendar events in (Pacific standard time).
+fn pacific() -> chrono::FixedOffset {
+    chrono::FixedOffset::west_opt(8 * 3600).expect("valid offset")
+}
+
+// ---------------------------------------------------------------------------
+// Clients
+// ---------------------------------------------------------------------------
+
+async fn list_clients(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    let param = |name: &str| query.get(name).map(String::as_str).unwrap_or_default();
+    let search = param("sSearch").to_lowercase();
+    let start: usize = param("iDisplayStart").parse().unwrap_or(0);
+    let length: usize = param("iDisplayLength").parse().unwrap_or(10);
+
+    let matching: Vec<&serde_json::Value> = state
+        .clients
+        .values()
+        .filter(|c| search.is_empty() || self::matches_client_search(c, &search))
+        .collect();
+
+    let page: Vec<&serde_json::Value> = matching.iter().skip(start).take(length).copied().collect();
+
+    Json(json!({
+        "sEcho": param("sEcho").parse::<u32>().unwrap_or(1),
+        "iTotalRecords": state.clients.len(),
+        "iTotalDisplayRecords": matching.len(),
+        "aaData": page,
+    }))
+    .into_response()
+}
+
+async fn client_detail(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Path(id): Path<u64>,
+) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+    let Some(client) = state.clients.get(&id) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let value = |name: &str| client[name].as_str().unwrap_or_default();
+    let mut controls = String::new();
+    for name in CLIENT_TEXT_INPUTS {
+        controls.push_str(&format!(
+            r#"<input type="text" id="{name}" name="{name}" value="{}"/>"#,
+            self::escape(value(name))
+        ));
+    }
+    for name in CLIENT_TEXTAREAS {
+        controls.push_str(&format!(
+            r#"<textarea id="{name}" name="{name}">{}</textarea>"#,
+            self::escape(value(name))
+        ));
+    }
+    for name in CLIENT_CHECKBOXES {
+        let checked = if client[name] == true {
+            r#" checked="checked""#
+        } else {
+            ""
+        };
+        controls.push_str(&format!(
+            r#"<input type="checkbox" id="{name}1" name="{name}" value="true"{checked}/><input type="hidden" name="_{name}" value="on"/>"#
+        ));
+    }
+
+    Html(format!(
+        r#"<html><body>
+<form id="client" method="post" action="/app/clients/{id}">
+{controls}
+<button type="submit">Save</button>
+</form>
+</body></html>"#
+    ))
+    .into_response()
+}
+
+async fn update_client(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Path(id): Path<u64>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Response {
+    let mut state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    let form = RecordedForm { id, fields };
+    if !state.clients.contains_key(&id) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    if form.get("lastName").unwrap_or_default().is_empty() {
+        state.rejected_forms.push(form);
+        return self::rejected_form(
+            &format!("/app/clients/{id}"),
+            &[("lastName", "Last name is required.")],
+        );
+    }
+    let Some(client) = state.clients.get_mut(&id) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    self::apply_client_form(client, &form);
+
+    // Requests embed their neighbor, so keep those copies in step.
+    let updated = client.clone();
+    for request in state.requests.values_mut() {
+        if request["client"]["id"] == id {
+            request["client"] = updated.clone();
+        }
+    }
+
+    state.client_updates.push(form);
+    Redirect::to(&format!("/app/clients/{id}")).into_response()
+}
+
+async fn create_client(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Response {
+    let mut state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    if !fields
+        .iter()
+        .any(|(name, value)| name == "lastName" && !value.is_empty())
+    {
+        state.rejected_forms.push(RecordedForm { id: 0, fields });
+        return self::rejected_form(
+            "/app/clients/new",
+            &[("lastName", "Last name is required.")],
+        );
+    }
+
+    let id = state.clients.keys().max().map_or(1, |max| max + 1);
+    let form = RecordedForm { id, fields };
+    let mut client = json!({ "id": id, "lastRequestDate": null });
+    self::apply_client_form(&mut client, &form);
+
+    state.clients.insert(id, client);
+    state.created_clients.push(form);
+    Redirect::to(&format!("/app/clients/{id}")).into_response()
+}
+
+async fn list_follow_ups(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    let param = |name: &str| query.get(name).map(String::as_str).unwrap_or_default();
+    let status = param("filterByStatus");
+    let start: usize = param("iDisplayStart").parse().unwrap_or(0);
+    let length: usize = param("iDisplayLength").parse().unwrap_or(10);
+
+    let matching: Vec<serde_json::Value> = state
+        .follow_ups
+        .values()
+        .filter(|f| match status {
+            "Open" => f["completed"] == false,
+            "Completed" => f["completed"] == true,
+            _ => true,
+        })
+        .map(|f| {
+            let mut f = f.clone();
+            let client_id = f["clientId"].as_u64().unwrap_or_default();
+            f["client"] = state.clients.get(&client_id).cloned().unwrap_or_default();
+            f
+        })
+        .collect();
+
+    let page: Vec<&serde_json::Value> = matching.iter().skip(start).take(length).collect();
+
+    Json(json!({
+        "sEcho": param("sEcho").parse::<u32>().unwrap_or(1),
+        "iTotalRecords": state.follow_ups.len(),
+        "iTotalDisplayRecords": matching.len(),
+        "aaData": page,
+    }))
+    .into_response()
+}
+
+async fn create_follow_up(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Path(client_id): Path<u64>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Response {
+    let mut state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+    if !state.clients.contains_key(&client_id) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    let form = RecordedForm {
+        id: client_id,
+        fields,
+    };
+    if form.get("followUpDate").unwrap_or_default().is_empty() {
+        state.rejected_forms.push(form);
+        return self::rejected_form(
+            &format!("/app/clients/{client_id}/followups/new"),
+            &[("followUpDate", "Follow-up date is required.")],
+        );
+    }
+    let id = state.follow_ups.keys().max().map_or(1, |max| max + 1);
+    let follow_up = self::follow_up_json(
+        id,
+        client_id,
+        form.get("followUpDate").unwrap_or_default(),
+        form.get("notes").unwrap_or_default(),
+    );
+    state.follow_ups.insert(id, follow_up);
+
+    state.created_follow_ups.push(form);
+    Redirect::to(&format!("/app/clients/{client_id}")).into_response()
+}
+
+fn follow_up_json(id: u64, client_id: u64, follow_up_date: &str, notes: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "clientId": client_id,
+        "followUpDate": follow_up_date,
+        "notes": notes,
+        "completed": false,
+        "dateCompleted": null,
+        "assignedToMember": null,
+    })
+}
+
+/// Copy the posted client form onto the client JSON. The form's field
+/// names are the JSON keys.
+fn apply_client_form(client: &mut serde_json::Value, form: &RecordedForm) {
+    for name in CLIENT_TEXT_INPUTS.iter().chain(&CLIENT_TEXTAREAS) {
+        if let Some(value) = form.get(name) {
+            client[*name] = json!(value);
+        }
+    }
+    for name in CLIENT_CHECKBOXES {
+        client[name] = json!(form.get(name) == Some("true"));
+    }
+}
+
+/// Loose stand-in for the neighbor search: name, address, or phone number
+/// compared by digits so `650-555-0100` finds `(650) 555-0100`.
+fn matches_client_search(client: &serde_json::Value, needle: &str) -> bool {
+    let text = |field: &str| client[field].as_str().unwrap_or_default().to_lowercase();
+    let digits = |s: &str| s.chars().filter(char::is_ascii_digit).collect::<String>();
+
+    let needle_digits = digits(needle);
+    let phone_match = needle_digits.len() >= 4
+        && ["homePhone", "mobilePhone"]
+            .iter()
+            .any(|field| digits(&text(field)).contains(&needle_digits));
+
+    phone_match
+        || ["firstName", "lastName", "streetAddressLine1", "city"]
+            .iter()
+            .any(|field| text(field).contains(needle))
+}
+
+// ---------------------------------------------------------------------------
+// Mileage and hours
+// ---------------------------------------------------------------------------
+
+async fn service_hours_page(State(state): State<Shared>, headers: HeaderMap) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+    Html("<html><body><h1>Mileage and Hours in Service</h1></body></html>").into_response()
+}
+
+async fn list_service_hours(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    let param = |name: &str| query.get(name).map(String::as_str).unwrap_or_default();
+    let start: usize = param("iDisplayStart").parse().unwrap_or(0);
+    let length: usize = param("iDisplayLength").parse().unwrap_or(10);
+
+    let page: Vec<&serde_json::Value> = state
+        .service_hours
+        .values()
+        .skip(start)
+        .take(length)
+        .collect();
+
+    Json(json!({
+        "sEcho": param("sEcho").parse::<u32>().unwrap_or(1),
+        "iTotalRecords": state.service_hours.len(),
+        "iTotalDisplayRecords": state.service_hours.len(),
+        "aaData": page,
+    }))
+    .into_response()
+}
+
+async fn create_service_hours(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Response {
+    let mut state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    if !fields
+        .iter()
+        .any(|(name, value)| name == "hoursInService" && value.parse::<f64>().is_ok())
+    {
+        state.rejected_forms.push(RecordedForm { id: 0, fields });
+        return self::rejected_form(
+            "/app/mileagehoursinservicelist/new",
+            &[("hoursInService", "Hours in service must be a number.")],
+        );
+    }
+
+    let id = state.service_hours.keys().max().map_or(1, |max| max + 1);
+    let form = RecordedForm { id, fields };
+    let number = |name: &str| {
+        form.get(name)
+            .and_then(|v| v.parse::<f64>().ok())
+            .unwrap_or_default()
+    };
+    let mut entry = self::service_hours_json(
+        &state,
+        id,
+        form.get("memberId").unwrap_or_default(),
+        form.get("dateOfService").unwrap_or_default(),
+        number("hoursInService"),
+        number("mileageInService"),
+    );
+    entry["notes"] = json!(form.get("notes").unwrap_or_default());
+    state.service_hours.insert(id, entry);
+
+    state.created_service_hours.push(form);
+    Redirect::to("/app/mileagehoursinservicelist").into_response()
+}
+
+fn service_hours_json(
+    state: &MockState,
+    id: u64,
+    member_id: &str,
+    date_of_service: &str,
+    hours: f64,
+    miles: f64,
+) -> serde_json::Value {
+    let member = state.members.iter().find(|m| m.id == member_id);
+    json!({
+        "id": id,
+        "memberId": member_id,
+        "member": member.map(|m| m.name.as_str()),
+        "dateOfService": date_of_service,
+        "hoursInService": hours,
+        "mileageInService": miles,
+        "notes": "",
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Assistance requests
+// ---------------------------------------------------------------------------
+
+async fn list_requests(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    let param = |name: &str| query.get(name).map(String::as_str).unwrap_or_default();
+    let status = param("filterByStatus");
+    let search = param("sSearch").to_lowercase();
+    let start: usize = param("iDisplayStart").parse().unwrap_or(0);
+    let length: usize = param("iDisplayLength").parse().unwrap_or(10);
+
+    let matching: Vec<&serde_json::Value> = state
+        .requests
+        .values()
+        .filter(|r| status.is_empty() || r["status"] == status)
+        .filter(|r| search.is_empty() || self::matches_search(r, &search))
+        .collect();
+
+    let page: Vec<serde_json::Value> = matching
+        .iter()
+        .skip(start)
+        .take(length)
+        .map(|r| {
+            // The neighbor carries their follow-ups, without the nested
+            // client the follow-up list adds.
+            let mut r = (*r).clone();
+            let client_id = r["client"]["id"].as_u64();
+            let follow_ups: Vec<&serde_json::Value> = state
+                .follow_ups
+                .values()
+                .filter(|f| f["clientId"].as_u64() == client_id)
+                .collect();
+            r["client"]["openFollowUp"] = json!(follow_ups.iter().any(|f| f["completed"] == false));
+            r["client"]["followUps"] = json!(follow_ups);
+            r
+        })
+        .collect();
+
+    Json(json!({
+        "sEcho": param("sEcho").parse::<u32>().unwrap_or(1),
+        "iTotalRecords": state.requests.len(),
+        "iTotalDisplayRecords": matching.len(),
+        "additionalData": {},
+        "aaData": page,
+    }))
+    .into_response()
+}
+
+/// Loose stand-in for ServWare's global search: request ID or neighbor name.
+fn matches_search(request: &serde_json::Value, needle: &str) -> bool {
+    let client = &request["client"];
+    [
+        request["id"].to_string(),
+        client["firstName"]
+            .as_str()
+            .unwrap_or_default()
+            .to_lowercase(),
+        client["lastName"]
+            .as_str()
+            .unwrap_or_default()
+            .to_lowercase(),
+    ]
+    .iter()
+    .any(|hay| hay.contains(needle))
+}
+
+async fn item_value(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    let type_id = query
+        .get("selectid")
+        .map(String::as_str)
+        .unwrap_or_default();
+    match state.item_values.get(type_id) {
+        Some(value) => Json(json!({ "monetaryValue": value })).into_response(),
+        None => Json(json!({})).into_response(),
+    }
+}
+
+async fn request_detail(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Path(id): Path<u64>,
+) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+    if !state.requests.contains_key(&id) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    let Some(form) = state.request_form(id) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let value = |name: &str| form.get(name).map(String::as_str).unwrap_or_default();
+
+    let mut controls = String::new();
+    controls.push_str(&self::select(
+        "status",
+        value("status"),
+        &[
+            ("Open", "Open"),
+            ("Completed", "Completed"),
+            ("Denied", "Denied"),
+        ],
+    ));
+    for (name, lookup) in [
+        ("denialReasonId", &state.denial_reasons),
+        ("referredFromOrganizationId", &state.referral_organizations),
+    ] {
+        // Keep a value that was posted but isn't seeded, as ServWare would
+        // for a since-retired entry.
+        let current = value(name);
+        let mut options = self::lookup_options(lookup);
+        if !options.iter().any(|(id, _)| *id == current) {
+            options.push((current, current));
+        }
+        controls.push_str(&self::select(name, current, &options));
+    }
+
+    let members: Vec<(&str, &str)> = std::iter::once(("", "-- Select --"))
+        .chain(
+            state
+                .members
+                .iter()
+                .map(|m| (m.id.as_str(), m.name.as_str())),
+        )
+        .collect();
+    for name in MEMBER_SELECTS {
+        controls.push_str(&self::select(name, value(name), &members));
+    }
+
+    for name in TEXT_INPUTS {
+        controls.push_str(&format!(
+            r#"<input type="text" id="{name}" name="{name}" value="{}"/>"#,
+            self::escape(value(name))
+        ));
+    }
+    for name in TEXTAREAS {
+        controls.push_str(&format!(
+            r#"<textarea id="{name}" name="{name}">{}</textarea>"#,
+            self::escape(value(name))
+        ));
+    }
+    for name in CHECKBOXES {
+        let checked = if value(name) == "true" {
+            r#" checked="checked""#
+        } else {
+            ""
+        };
+        controls.push_str(&format!(
+            r#"<input type="checkbox" id="{name}1" name="{name}" value="true"{checked}/><input type="hidden" name="_{name}" value="on"/>"#
+        ));
+    }
+
+    Html(format!(
+        r#"<html><body>
+<form id="assistanceRequest" method="post" action="/app/assistancerequests/{id}">
+{controls}
+<input type="file" name="files"/>
+<button type="submit">Save</button>
+</form>
+</body></html>"#
+    ))
+    .into_response()
+}
+
+/// Accepts the form urlencoded, or as multipart when it carries a file.
+async fn update_request(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Path(id): Path<u64>,
+    request: Request,
+) -> Response {
+    let Some((fields, uploads)) = self::read_form(request).await else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+
+    let mut state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+    if !state.requests.contains_key(&id) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    for (name, content_type, contents) in uploads {
+        let file_id = state.files.len() as u64 + 1;
+        state.files.push(MockFile {
+            id: file_id,
+            request_id: id,
+            name,
+            content_type,
+            contents,
+        });
+    }
+
+    let form = RecordedForm { id, fields };
+    if form.get("status") == Some("Denied")
+        && form.get("denialReasonId").unwrap_or_default().is_empty()
+    {
+        let action = format!("/app/assistancerequests/{id}");
+        state.rejected_forms.push(form);
+        return self::rejected_form(
+            &action,
+            &[("denialReasonId", "A denial reason is required.")],
+        );
+    }
+    if !self::apply_request_form(&mut state, &form) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    state.request_updates.push(form);
+    Redirect::to(&format!("/app/assistancerequests/{id}")).into_response()
+}
+
+async fn create_request(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Response {
+    let mut state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    if !fields
+        .iter()
+        .any(|(name, value)| name == "dateRequested" && !value.is_empty())
+    {
+        state.rejected_forms.push(RecordedForm { id: 0, fields });
+        return self::rejected_form(
+            "/app/assistancerequests/new",
+            &[("dateRequested", "Date requested is required.")],
+        );
+    }
+
+    let id = state.requests.keys().max().map_or(1, |max| max + 1);
+    let form = RecordedForm { id, fields };
+    let Some(client) = form
+        .get("clientId")
+        .and_then(|client_id| client_id.parse().ok())
+        .and_then(|client_id: u64| state.clients.get(&client_id))
+        .cloned()
+    else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let text = |field: &str| client[field].as_str().unwrap_or_default().to_string();
+    let mut request = MockRequest {
+        id,
+        status: "Open".into(),
+        date_requested: form.get("dateRequested").unwrap_or_default().into(),
+        client_id: client["id"].as_u64().unwrap_or_default(),
+        first_name: text("firstName"),
+        last_name: text("lastName"),
+        street_address: text("streetAddressLine1"),
+        city: text("city"),
+        household_count: 1,
+    }
+    .to_json();
+    request["client"] = client;
+    state.requests.insert(id, request);
+    self::apply_request_form(&mut state, &form);
+
+    state.created_requests.push(form);
+    Redirect::to(&format!("/app/assistancerequests/{id}")).into_response()
+}
+
+/// Apply a posted request form to the stored request. `false` if there is
+/// no such request.
+fn apply_request_form(state: &mut MockState, form: &RecordedForm) -> bool {
+    let id = form.id;
+    let Some(request) = state.requests.get_mut(&id) else {
+        return false;
+    };
+
+    if let Some(status) = form.get("status") {
+        request["status"] = json!(status);
+    }
+    if let Some(first_name) = form.get("clientFirstName") {
+        request["client"]["firstName"] = json!(first_name);
+    }
+    if let Some(last_name) = form.get("clientLastName") {
+        request["client"]["lastName"] = json!(last_name);
+    }
+    if let Some(note) = form.get("requestNote") {
+        request["requestNote"] = json!(note);
+    }
+    if let Some(notes) = form.get("visitNotes") {
+        request["visitNotes"] = json!(notes);
+    }
+    if let Some(date) = form.get("visitScheduledDate") {
+        request["visitScheduledDate"] = json!(date);
+    }
+    request["homeVisitRequired"] = json!(form.get("homeVisitRequired") == Some("true"));
+    request["visitCompleted"] = json!(form.get("visitCompleted") == Some("true"));
+
+    // An unchecked checkbox only sends its `_name=on` companion.
+    let mut posted: HashMap<String, String> = HashMap::new();
+    for (name, value) in &form.fields {
+        match name.strip_prefix('_') {
+            Some(checkbox) => {
+                posted.entry(checkbox.to_string()).or_default();
+            }
+            None => {
+                posted.insert(name.clone(), value.clone());
+            }
+        }
+    }
+    state.request_forms.entry(id).or_default().extend(posted);
+    true
+}
+
+/// Form fields in the order sent, plus any `(file name, content type,
+/// contents)` uploads. `None` if the body can't be read.
+async fn read_form(
+    request: Request,
+) -> Option<(Vec<(String, String)>, Vec<(String, String, Vec<u8>)>)> {
+    let multipart = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("multipart/form-data"));
+    if !multipart {
+        let Form(fields) = Form::<Vec<(String, String)>>::from_request(request, &())
+            .await
+            .ok()?;
+        return Some((fields, Vec::new()));
+    }
+
+    let mut body = Multipart::from_request(request, &()).await.ok()?;
+    let mut fields = Vec::new();
+    let mut uploads = Vec::new();
+    while let Some(field) = body.next_field().await.ok()? {
+        let name = field.name().unwrap_or_default().to_string();
+        match field.file_name().map(str::to_string) {
+            Some(file_name) if !file_name.is_empty() => {
+                let content_type = field
+                    .content_type()
+                    .unwrap_or("application/octet-stream")
+                    .to_string();
+                let contents = field.bytes().await.ok()?.to_vec();
+                uploads.push((file_name, content_type, contents));
+            }
+            _ => fields.push((name, field.text().await.ok()?)),
+        }
+    }
+    Some((fields, uploads))
+}
+
+async fn list_files(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Path(id): Path<u64>,
+) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    let rows: String = state
+        .files
+        .iter()
+        .filter(|f| f.request_id == id)
+        .map(|f| {
+            let link = format!("/app/assistancerequests/{id}/files/{}", f.id);
+            format!(
+                r#"<tr><td><a href="{link}">{}</a></td><td>{} bytes</td><td><a href="{link}/delete">Delete</a></td></tr>"#,
+                self::escape(&f.name),
+                f.contents.len()
+            )
+        })
+        .collect();
+    Html(format!(r#"<table class="table">{rows}</table>"#)).into_response()
+}
+
+async fn list_approvals(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Path(id): Path<u64>,
+) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    let approvals = state
+        .approvals
+        .get(&id)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let rows: String = if approvals.is_empty() {
+        r#"<tr><td colspan="4">No approvals required</td></tr>"#.to_string()
+    } else {
+        approvals
+            .iter()
+            .map(|a| {
+                format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    self::escape(&a.approver),
+                    self::escape(&a.status),
+                    self::escape(&a.date),
+                    self::escape(&a.notes)
+                )
+            })
+            .collect()
+    };
+    Html(format!(
+        r#"<table class="table"><thead><tr><th>Approver</th><th>Status</th><th>Date</th><th>Notes</th></tr></thead><tbody>{rows}</tbody></table>"#
+    ))
+    .into_response()
+}
+
+async fn download_file(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Path((id, file_id)): Path<(u64, u64)>,
+) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    match state
+        .files
+        .iter()
+        .find(|f| f.request_id == id && f.id == file_id)
+    {
+        Some(file) => (
+            [(header::CONTENT_TYPE, file.content_type.clone())],
+            file.contents.clone(),
+        )
+            .into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn assistance_item_form(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Path(id): Path<u64>,
+) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+    let types = self::select(
+        "assistanceTypeId",
+        "",
+        &self::lookup_options(&state.assistance_types),
+    );
+    Html(format!(
+        r#"<html><body><form method="post" action="/app/assistancerequests/{id}/assistanceitems/new">{types}</form></body></html>"#
+    ))
+    .into_response()
+}
+
+async fn add_assistance_item(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Path(id): Path<u64>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Response {
+    let mut state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    let form = RecordedForm { id, fields };
+    if !state.requests.contains_key(&id) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let mut errors = Vec::new();
+    if form
+        .get("monetaryValue")
+        .and_then(|v| v.parse::<f64>().ok())
+        .is_none()
+    {
+        errors.push(("monetaryValue", "Monetary value must be a number."));
+    }
+    if form.get("dateProvided").unwrap_or_default().is_empty() {
+        errors.push(("dateProvided", "Date provided is required."));
+    }
+    if !errors.is_empty() {
+        let action = format!("/app/assistancerequests/{id}/assistanceitems/new");
+        state.rejected_forms.push(form);
+        return self::rejected_form(&action, &errors);
+    }
+
+    let item_id = state.next_item_id;
+    let Some(request) = state.requests.get_mut(&id) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let number = |name: &str| {
+        form.get(name)
+            .and_then(|v| v.parse::<f64>().ok())
+            .unwrap_or_default()
+    };
+    let item = json!({
+        "id": item_id,
+        "monetaryValue": number("monetaryValue"),
+        "quantity": number("quantity") as u32,
+        "dateProvided": form.get("dateProvided").unwrap_or_default(),
+        "notes": form.get("notes").unwrap_or_default(),
+        "assistanceType": { "id": number("assistanceTypeId") as u64 },
+        "pending": form.get("pending") == Some("true"),
+        "checkRequested": form.get("checkRequested") == Some("true"),
+        "payeeName": form.get("payeeName").unwrap_or_default(),
+        "promisedDate": form.get("promisedDate").unwrap_or_default(),
+        "datePaid": form.get("datePaid").unwrap_or_default(),
+        "checkNumber": form.get("checkNumber").unwrap_or_default(),
+    });
+    if let Some(items) = request["assistanceItems"].as_array_mut() {
+        items.push(item);
+    }
+
+    let target = match form.get("action") {
+        Some("saveadd") => format!("/app/assistancerequests/{id}/assistanceitems/new"),
+        _ => format!("/app/assistancerequests/{id}"),
+    };
+
+    state.next_item_id += 1;
+    state.assistance_items.push(form);
+    Redirect::to(&target).into_response()
+}
+
+/// Pending items across all requests, in a table like ServWare's pending
+/// assistance page.
+async fn pending_assistance(State(state): State<Shared>, headers: HeaderMap) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    let mut rows = String::new();
+    for (request_id, request) in &state.requests {
+        let Some(items) = request["assistanceItems"].as_array() else {
+            continue;
+        };
+        let client = &request["client"];
+        let neighbor = format!(
+            "{}, {}",
+            client["lastName"].as_str().unwrap_or_default(),
+            client["firstName"].as_str().unwrap_or_default()
+        );
+        for item in items.iter().filter(|i| i["pending"] == true) {
+            let type_id = item["assistanceType"]["id"].as_u64().unwrap_or_default();
+            let assistance_type = state
+                .assistance_types
+                .iter()
+                .find(|t| t.id == type_id.to_string())
+                .map_or("", |t| t.name.as_str());
+            let text = |name: &str| self::escape(item[name].as_str().unwrap_or_default());
+            rows.push_str(&format!(
+                r#"<tr><td><a href="/app/assistancerequests/{request_id}">{request_id}</a></td><td>{}</td><td>{}</td><td>${:.2}</td><td>{}</td><td>{}</td><td>{}</td><td><a href="/app/assistancerequests/{request_id}/assistanceitems/{}">Edit</a></td></tr>"#,
+                self::escape(&neighbor),
+                self::escape(assistance_type),
+                item["monetaryValue"].as_f64().unwrap_or_default(),
+                text("payeeName"),
+                text("promisedDate"),
+                text("dateProvided"),
+                item["id"]
+            ));
+        }
+    }
+    Html(format!(
+        r#"<html><body><table class="table"><thead><tr><th>Request</th><th>Neighbor</th><th>Assistance Type</th><th>Amount</th><th>Payee Name</th><th>Promised Date</th><th>Date Provided</th><th></th></tr></thead><tbody>{rows}</tbody></table></body></html>"#
+    ))
+    .into_response()
+}
+
+async fn assistance_item(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Path((id, item_id)): Path<(u64, u64)>,
+) -> Response {
+    let state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+    let Some(item) = state.assistance_item(id, item_id) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let mut controls = self::select(
+        "assistanceTypeId",
+        &item["assistanceType"]["id"].to_string(),
+        &self::lookup_options(&state.assistance_types),
+    );
+    for name in ["monetaryValue", "quantity"] {
+        controls.push_str(&format!(
+            r#"<input type="text" id="{name}" name="{name}" value="{}"/>"#,
+            item[name]
+        ));
+    }
+    for name in [
+        "dateProvided",
+        "promisedDate",
+        "datePaid",
+        "checkNumber",
+        "payeeName",
+    ] {
+        controls.push_str(&format!(
+            r#"<input type="text" id="{name}" name="{name}" value="{}"/>"#,
+            self::escape(item[name].as_str().unwrap_or_default())
+        ));
+    }
+    controls.push_str(&format!(
+        r#"<textarea id="notes" name="notes">{}</textarea>"#,
+        self::escape(item["notes"].as_str().unwrap_or_default())
+    ));
+    for name in ["pending", "checkRequested"] {
+        let checked = if item[name] == true {
+            r#" checked="checked""#
+        } else {
+            ""
+        };
+        controls.push_str(&format!(
+            r#"<input type="checkbox" id="{name}1" name="{name}" value="true"{checked}/><input type="hidden" name="_{name}" value="on"/>"#
+        ));
+    }
+
+    // The page's delete button is a form of its own, ahead of the edit form.
+    Html(format!(
+        r#"<html><body><form method="post" action="/app/assistancerequests/{id}/assistanceitems/{item_id}/delete"><input type="hidden" name="confirmDelete" value="true"/></form><form method="post" action="/app/assistancerequests/{id}/assistanceitems/{item_id}">{controls}<button type="submit" name="action" value="save">Save</button></form></body></html>"#
+    ))
+    .into_response()
+}
+
+async fn update_assistance_item(
+    State(state): State<Shared>,
+    headers: HeaderMap,
+    Path((id, item_id)): Path<(u64, u64)>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Response {
+    let mut state = lock(&state);
+    if !self::has_session(&state, &headers) {
+        return self::session_timeout();
+    }
+
+    let form = RecordedForm {
+        id: item_id,
+        fields,
+    };
+    let Some(item) = state.assistance_item_mut(id, item_id) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    for name in [
+        "dateProvided",
+        "promisedDate",
+        "datePaid",
+        "checkNumber",
+        "payeeName",
+        "notes",
+    ] {
+        if let Some(value) = form.get(name) {
+            item[name] = json!(value);
+        }
+    }
+    item["pending"] = json!(form.get("pending") == Some("true"));
+    item["checkRequested"] = json!(form.get("checkRequested") == Some("true"));
+
+    state.item_updates.push(form);
+    Redirect::to(&format!("/app/assistancerequests/{id}")).into_response()
+}
+
+// ---------------------------------------------------------------------------
+// HTML
+// ---------------------------------------------------------------------------
+
+/// A lookup table as `<select>` options, after the empty placeholder.
+fn lookup_options(lookup: &[LookupOption]) -> Vec<(&str, &str)> {
+    std::iter::once(("", "-- Select --"))
+        .chain(lookup.iter().map(|o| (o.id.as_str(), o.name.as_str())))
+        .collect()
+}
+
+/// A `<select>` with `selected` marked the way Spring's form tags render it.
+fn select(name: &str, selected: &str, options: &[(&str, &str)]) -> String {
+    let options: String = options
+        .iter()
+        .map(|(value, label)| {
+            let marker = if *value == selected {
+                r#" selected="selected""#
+            } else {
+                ""
+            };
+            format!(
+                r#"<option value="{}"{marker}>{}</option>"#,
+                self::escape(value),
+                self::escape(label)
+            )
+        })
+        .collect();
+    format!(r#"<select id="{name}" name="{name}">{options}</select>"#)
+}
+
+/// How ServWare answers a form that fails validation: a 200 showing the
+/// form again, each message in the span Spring's `<form:errors>` renders.
+fn rejected_form(action: &str, errors: &[(&str, &str)]) -> Response {
+    let spans: String = errors
+        .iter()
+        .map(|(field, message)| {
+            format!(
+                r#"<span id="{field}.errors" class="error">{}</span>"#,
+                self::escape(message)
+            )
+        })
+        .collect();
+    Html(format!(
+        r#"<html><body><form method="post" action="{action}">{spans}<button type="submit">Save</button></form></body></html>"#
+    ))
+    .into_response()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -16,14 +16,27 @@ Plan:
 */
 
 use std::path::Path;
+use std::pin::pin;
 
 use anyhow::Context;
+use chrono::Utc;
+use futures_util::TryStreamExt;
 use serde::Deserialize;
 use serde::Serialize;
 
-use crate::api::ServWare;
+use crate::api::ServWareApi;
+use crate::api::fetch_approvals::Approval;
+use crate::api::fetch_calendar::CalendarEvent;
+use crate::api::fetch_calendar::CalendarFeed;
+use crate::api::fetch_client_detail::ClientDetail;
+use crate::api::fetch_item_value::ItemValue;
+use crate::api::fetch_request_detail::RequestDetail;
+use crate::api::fetch_requests::Client;
 use crate::api::fetch_requests::FetchRequestsParams;
+use crate::api::follow_ups::NewFollowUp;
+use crate::api::service_hours::NewServiceHours;
 use crate::api::update_assistance::UpdateAssistanceInput;
+use crate::api::update_client::UpdateClientInput;
 use crate::api::update_request::UpdateRequestInput;
 
 // ---------------------------------------------------------------------------
@@ -35,7 +48,8 @@ const VISIT_NOTES: &str = "<p>Delivered food and gift cards</p>";
 
 /// ServWare assistance type ID for Second Harvest food.
 const SECOND_HARVEST_TYPE_ID: &str = "16542";
-/// Monetary value recorded for a Second Harvest food delivery.
+/// Monetary value recorded for a Second Harvest food delivery unless the
+/// CSV (or, when asked for, ServWare's configured default) gives another.
 const SECOND_HARVEST_VALUE: &str = "70";
 
 /// ServWare assistance type ID for gift cards.
@@ -55,6 +69,10 @@ pub struct OpenRequest {
 
     // Field(s) that don't map to any specific ServWare entry.
     pub gift_card_dollars: u32,
+    /// Overrides the Second Harvest value for this row. Left empty, ServWare's
+    /// configured default is used.
+    #[serde(default)]
+    pub second_harvest_dollars: Option<u32>,
     pub merged_address: String,
 
     // Fields from `AssistanceRequest`.
@@ -62,6 +80,94 @@ pub struct OpenRequest {
     pub req_status: String,
     pub req_date_created: String,
     pub req_calculated_household_count: u32,
+
+    /// Approval chain summary, e.g. `"Jane Doe: Approved; Bob Roe: Pending"`.
+    /// Only filled in when exporting with approvals.
+    #[serde(default)]
+    pub req_approvals: String,
+
+    /// The neighbor's open follow-ups, e.g. `"03/15/2026 call back"`, so
+    /// callers see them while planning deliveries.
+    #[serde(default)]
+    pub neighbor_follow_ups: String,
+}
+
+/// One neighbor's contact corrections, e.g. a new phone number learned on
+/// the delivery call. Empty cells keep the value already in ServWare.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContactCorrection {
+    pub neighbor_id: u64,
+    pub home_phone: Option<String>,
+    pub mobile_phone: Option<String>,
+    pub email_address: Option<String>,
+    pub street_address_line1: Option<String>,
+    pub street_address_line2: Option<String>,
+    pub city: Option<String>,
+    pub postal_code: Option<String>,
+}
+
+/// One request to open, from a walk-in or a paper intake sheet. Without a
+/// `neighbor_id`, a new neighbor is created from the name, phone and
+/// address columns first.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IntakeRow {
+    #[serde(default)]
+    pub neighbor_id: Option<u64>,
+    #[serde(default)]
+    pub first_name: String,
+    #[serde(default)]
+    pub last_name: String,
+    #[serde(default)]
+    pub home_phone: String,
+    #[serde(default)]
+    pub mobile_phone: String,
+    #[serde(default)]
+    pub street_address_line1: String,
+    #[serde(default)]
+    pub city: String,
+    #[serde(default)]
+    pub state_code: String,
+    #[serde(default)]
+    pub postal_code: String,
+    /// `MM/DD/YYYY`; today when left empty.
+    #[serde(default)]
+    pub date_requested: Option<String>,
+    #[serde(default)]
+    pub request_note: String,
+}
+
+/// One pending assistance item for the treasurer. Fill in `check_number`
+/// (and optionally `date_paid`) once the check is written; rows left blank
+/// stay pending.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PendingPayment {
+    pub req_id: u64,
+    pub item_id: u64,
+    pub neighbor: String,
+    pub assistance_type: String,
+    pub amount: Option<f64>,
+    pub payee_name: String,
+    pub promised_date: String,
+    /// `MM/DD/YYYY`; today when left empty.
+    #[serde(default)]
+    pub date_paid: Option<String>,
+    #[serde(default)]
+    pub check_number: String,
+}
+
+/// Hours and miles one volunteer served outside any request, e.g. a pantry
+/// shift. `volunteer_id` is the member ID from `volunteers.csv`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HoursRow {
+    pub volunteer_id: String,
+    /// `MM/DD/YYYY`.
+    pub date: String,
+    pub hours: f64,
+    /// Empty when no miles were driven.
+    #[serde(default)]
+    pub miles: Option<f64>,
+    #[serde(default)]
+    pub notes: String,
 }
 
 fn gift_card_dollars(family_size: u32) -> u32 {
@@ -79,18 +185,15 @@ fn gift_card_dollars(family_size: u32) -> u32 {
 // Public functions
 // ---------------------------------------------------------------------------
 
-pub async fn members_to_csv(client: &ServWare, csv: &Path) -> anyhow::Result<()> {
+pub async fn members_to_csv(client: &impl ServWareApi, csv: &Path) -> anyhow::Result<()> {
     let mut writer = csv::Writer::from_path(csv)?;
 
     // Find an arbitrary request from which to scrape volunteer names.
-    let reqs = client
-        .fetch_requests(&FetchRequestsParams::new_open_asc())
-        .await
-        .context("failed to fetch open requests")?;
-
+    let mut reqs = pin!(client.stream_requests(FetchRequestsParams::new_open_asc()));
     let first = reqs
-        .aa_data
-        .first()
+        .try_next()
+        .await
+        .context("failed to fetch open requests")?
         .context("no open requests found to scrape member list from")?;
     let request_id = first.id;
 
@@ -103,30 +206,44 @@ pub async fn members_to_csv(client: &ServWare, csv: &Path) -> anyhow::Result<()>
 }
 
 /// Fetches all open requests and writes them to a (truncated)
-/// csv at the given path.
-pub async fn requests_to_csv(client: &ServWare, csv: &Path) -> anyhow::Result<()> {
+/// csv at the given path. With `with_approvals`, also fetches each
+/// request's approval chain and summarizes it in `req_approvals`.
+pub async fn requests_to_csv(
+    client: &impl ServWareApi,
+    csv: &Path,
+    with_approvals: bool,
+) -> anyhow::Result<()> {
     let mut writer = csv::Writer::from_path(csv)?;
 
-    let reqs = client
-        .fetch_requests(&FetchRequestsParams::new_open_asc())
-        .await?;
-    for req in reqs.aa_data {
+    let mut reqs = pin!(client.stream_requests(FetchRequestsParams::new_open_asc()));
+    while let Some(req) = reqs.try_next().await? {
+        let req_approvals = if with_approvals {
+            let approvals = client.fetch_approvals(req.id).await?;
+            self::approval_summary(&approvals)
+        } else {
+            String::new()
+        };
+
+        let neighbor_follow_ups = self::follow_up_summary(&req.client);
         let open = OpenRequest {
             req_id: req.id,
             req_date_created: req.date_created,
             req_status: req.status,
             req_calculated_household_count: req.calculated_household_count,
+            req_approvals,
 
             merged_address: format!(
                 "{} {}, {}, {}",
                 req.street_address_line1, req.street_address_line2, req.city, req.state_code
             ),
             gift_card_dollars: self::gift_card_dollars(req.calculated_household_count),
+            second_harvest_dollars: None,
 
             neighbor_id: req.client.id,
             neighbor_first_name: req.client.first_name,
             neighbor_last_name: req.client.last_name,
             neighbor_last_request_date: req.client.last_request_date,
+            neighbor_follow_ups,
         };
 
         writer.serialize(open)?;
@@ -138,7 +255,11 @@ pub async fn requests_to_csv(client: &ServWare, csv: &Path) -> anyhow::Result<()
 
 /// Updates ServWare to mark every request in the CSV as complete,
 /// assigning the given volunteer and visit date.
-pub async fn update_complete(client: &ServWare, csv: &Path, member_id: &str) -> anyhow::Result<()> {
+pub async fn update_complete(
+    client: &impl ServWareApi,
+    csv: &Path,
+    member_id: &str,
+) -> anyhow::Result<()> {
     let visit_date = chrono::Local::now().format("%m/%d/%Y").to_string();
     tracing::info!("using visit date: {visit_date}");
 
@@ -166,9 +287,106 @@ pub async fn update_complete(client: &ServWare, csv: &Path, member_id: &str) ->
     Ok(())
 }
 
+/// Applies every contact correction in the CSV to the neighbor's ServWare
+/// record.
+pub async fn update_contacts(client: &impl ServWareApi, csv: &Path) -> anyhow::Result<()> {
+    let mut reader = csv::Reader::from_path(csv)?;
+    for row in reader.deserialize() {
+        let row: ContactCorrection = row?;
+        let update = UpdateClientInput {
+            home_phone: row.home_phone,
+            mobile_phone: row.mobile_phone,
+            email_address: row.email_address,
+            street_address_line1: row.street_address_line1,
+            street_address_line2: row.street_address_line2,
+            city: row.city,
+            postal_code: row.postal_code,
+            ..Default::default()
+        };
+
+        match client.update_client(row.neighbor_id, &update).await {
+            Ok(()) => tracing::info!("updated contact details of neighbor {}", row.neighbor_id),
+            // A neighbor merged or deleted since the CSV was made shouldn't
+            // hold up the rest.
+            Err(e) if e.is_not_found() => {
+                tracing::warn!("skipping neighbor {}: {e}", row.neighbor_id);
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Ok(())
+}
+
+/// Opens a request for every row of an intake CSV, creating the neighbor
+/// first when the row has no `neighbor_id`.
+///
+/// Nothing is checked against requests already open, so running the same
+/// CSV twice opens every request (and adds every new neighbor) twice.
+pub async fn intake(client: &impl ServWareApi, csv: &Path) -> anyhow::Result<()> {
+    let today = chrono::Local::now().format("%m/%d/%Y").to_string();
+
+    let mut reader = csv::Reader::from_path(csv)?;
+    for row in reader.deserialize() {
+        let row: IntakeRow = row?;
+        let neighbor = match row.neighbor_id {
+            Some(id) => client
+                .fetch_client_detail(id)
+                .await
+                .with_context(|| format!("failed to look up neighbor {id}"))?,
+            None => {
+                let mut neighbor = ClientDetail {
+                    first_name: row.first_name.clone(),
+                    last_name: row.last_name.clone(),
+                    home_phone: row.home_phone.clone(),
+                    mobile_phone: row.mobile_phone.clone(),
+                    street_address_line1: row.street_address_line1.clone(),
+                    city: row.city.clone(),
+                    state_code: row.state_code.clone(),
+                    postal_code: row.postal_code.clone(),
+                    ..Default::default()
+                };
+                neighbor.id = client.create_client(&neighbor).await?;
+                tracing::info!(
+                    "created neighbor {} ({} {})",
+                    neighbor.id,
+                    neighbor.first_name,
+                    neighbor.last_name
+                );
+                neighbor
+            }
+        };
+
+        let request = RequestDetail {
+            status: "Open".to_string(),
+            client_first_name: neighbor.first_name.clone(),
+            client_last_name: neighbor.last_name.clone(),
+            date_requested: row.date_requested.unwrap_or_else(|| today.clone()),
+            request_note: match row.request_note.trim() {
+                "" => String::new(),
+                note => format!("<p>{}</p>", self::escape_html(note)),
+            },
+            ..Default::default()
+        };
+        let request_id = client.create_request(neighbor.id, &request).await?;
+        tracing::info!("opened request {request_id} for neighbor {}", neighbor.id);
+    }
+
+    Ok(())
+}
+
 /// Adds two assistance items (Second Harvest food + gift cards) to every
 /// request in the CSV.
-pub async fn add_assistance(client: &ServWare, csv: &Path) -> anyhow::Result<()> {
+///
+/// Second Harvest is recorded at the row's `second_harvest_dollars`, or
+/// else at [`SECOND_HARVEST_VALUE`]. With `server_value`, ServWare's
+/// configured default is looked up instead; see [`server_item_value`] for
+/// why that's opt-in.
+pub async fn add_assistance(
+    client: &impl ServWareApi,
+    csv: &Path,
+    server_value: bool,
+) -> anyhow::Result<()> {
     let date_provided = chrono::Local::now().format("%m/%d/%Y").to_string();
     println!("using date provided: {date_provided}");
 
@@ -178,10 +396,23 @@ pub async fn add_assistance(client: &ServWare, csv: &Path) -> anyhow::Result<()>
         let client_id = row.neighbor_id.to_string();
 
         // 1. Second Harvest food
+        let second_harvest_value = match row.second_harvest_dollars {
+            Some(dollars) => dollars.to_string(),
+            None if server_value => {
+                self::server_item_value(
+                    client,
+                    SECOND_HARVEST_TYPE_ID,
+                    row.neighbor_id,
+                    SECOND_HARVEST_VALUE,
+                )
+                .await?
+            }
+            None => SECOND_HARVEST_VALUE.to_string(),
+        };
         let second_harvest = UpdateAssistanceInput::new(
             SECOND_HARVEST_TYPE_ID,
             &client_id,
-            SECOND_HARVEST_VALUE,
+            &second_harvest_value,
             "1",
             &date_provided,
         );
@@ -189,7 +420,7 @@ pub async fn add_assistance(client: &ServWare, csv: &Path) -> anyhow::Result<()>
             .update_assistance(row.req_id, &second_harvest)
             .await?;
         tracing::info!(
-            "  request {}: added Second Harvest (${SECOND_HARVEST_VALUE})",
+            "  request {}: added Second Harvest (${second_harvest_value})",
             row.req_id
         );
 
@@ -211,3 +442,550 @@ pub async fn add_assistance(client: &ServWare, csv: &Path) -> anyhow::Result<()>
 
     Ok(())
 }
+
+/// Writes the conference's assistance types, denial reasons and referral
+/// organizations to one CSV each in `dir`, so their IDs can be looked up.
+pub async fn lookups_to_csv(client: &impl ServWareApi, dir: &Path) -> anyhow::Result<()> {
+    // Any request will do; the dropdowns are the same on every one.
+    let any_status = FetchRequestsParams {
+        filter_by_status: String::new(),
+        display_length: 1,
+        ..FetchRequestsParams::new_open_asc()
+    };
+    let mut reqs = pin!(client.stream_requests(any_status));
+    let request = reqs
+        .try_next()
+        .await
+        .context("failed to fetch requests")?
+        .context("no requests found to scrape lookups from")?;
+
+    let lookups = client.fetch_lookups(request.id).await?;
+    std::fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    for (file, options) in [
+        ("assistance_types.csv", &lookups.assistance_types),
+        ("denial_reasons.csv", &lookups.denial_reasons),
+        (
+            "referral_organizations.csv",
+            &lookups.referral_organizations,
+        ),
+    ] {
+        let path = dir.join(file);
+        let mut writer = csv::Writer::from_path(&path)?;
+        for option in options {
+            writer.serialize(option)?;
+        }
+        writer.flush()?;
+        tracing::info!("wrote {} entries to {}", options.len(), path.display());
+    }
+
+    Ok(())
+}
+
+/// Prints the neighbors whose name, phone number or address matches
+/// `query`, one per line, e.g. to look up a walk-in neighbor.
+pub async fn print_clients(client: &impl ServWareApi, query: &str) -> anyhow::Result<()> {
+    let clients = client.search_clients(query).await?;
+    if clients.is_empty() {
+        println!("no neighbors match {query:?}");
+    }
+    for c in clients {
+        let phone = [&c.mobile_phone, &c.home_phone]
+            .into_iter()
+            .find(|p| !p.is_empty())
+            .map_or("-", String::as_str);
+        let last_request = match c.last_request_date.as_str() {
+            "" => "never",
+            date => date,
+        };
+        println!(
+            "{:>8}  {}, {}  {}, {}  {phone}  last request {last_request}",
+            c.id, c.last_name, c.first_name, c.street_address_line1, c.city
+        );
+    }
+
+    Ok(())
+}
+
+/// Prints open follow-ups, soonest first: all of them, or only those due
+/// within `days` (overdue ones included).
+pub async fn print_follow_ups(client: &impl ServWareApi, days: Option<u32>) -> anyhow::Result<()> {
+    let follow_ups = match days {
+        Some(days) => {
+            let by = chrono::Local::now().date_naive() + chrono::Days::new(days.into());
+            client.due_follow_ups(by).await?
+        }
+        None => client.open_follow_ups().await?,
+    };
+    if follow_ups.is_empty() {
+        println!("no open follow-ups");
+    }
+    for f in follow_ups {
+        let neighbor = f.client.unwrap_or_default();
+        println!(
+            "{}  {:>8}  {}, {}  {}",
+            f.follow_up_date, neighbor.id, neighbor.last_name, neighbor.first_name, f.notes
+        );
+    }
+
+    Ok(())
+}
+
+/// Adds a follow-up due `days` from today to a neighbor.
+pub async fn add_follow_up(
+    client: &impl ServWareApi,
+    neighbor_id: u64,
+    days: u32,
+    notes: &str,
+) -> anyhow::Result<()> {
+    let due = chrono::Local::now().date_naive() + chrono::Days::new(days.into());
+    let follow_up = NewFollowUp::new(due, notes);
+    client.create_follow_up(neighbor_id, &follow_up).await?;
+    println!(
+        "follow-up for neighbor {neighbor_id} due {}",
+        follow_up.follow_up_date
+    );
+
+    Ok(())
+}
+
+/// Prints the conference activity report for `from` through `to`, one
+/// line per figure under its section heading.
+pub async fn print_activity_report(
+    client: &impl ServWareApi,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+) -> anyhow::Result<()> {
+    let mut section = None;
+    for row in client.fetch_activity_report(from, to).await? {
+        if section.as_ref() != Some(&row.section) {
+            println!("{}", row.section);
+            section = Some(row.section.clone());
+        }
+        let count = row.count.map(|c| c.to_string()).unwrap_or_default();
+        let amount = row.amount.map(|a| format!("${a:.2}")).unwrap_or_default();
+        println!("  {:<40} {count:>8} {amount:>12}", row.description);
+    }
+
+    Ok(())
+}
+
+pub async fn activity_report_to_csv(
+    client: &impl ServWareApi,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    csv: &Path,
+) -> anyhow::Result<()> {
+    let rows = client.fetch_activity_report(from, to).await?;
+    let mut writer = csv::Writer::from_path(csv)?;
+    for row in &rows {
+        writer.serialize(row)?;
+    }
+    writer.flush()?;
+    tracing::info!("wrote {} report lines to {}", rows.len(), csv.display());
+
+    Ok(())
+}
+
+pub async fn activity_report_to_json(
+    client: &impl ServWareApi,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    json: &Path,
+) -> anyhow::Result<()> {
+    let rows = client.fetch_activity_report(from, to).await?;
+    let file = std::fs::File::create(json)
+        .with_context(|| format!("failed to create {}", json.display()))?;
+    serde_json::to_writer_pretty(file, &rows)?;
+
+    Ok(())
+}
+
+/// Writes every pending assistance item to a CSV for the treasurer to
+/// reconcile against the checkbook.
+pub async fn pending_to_csv(client: &impl ServWareApi, csv: &Path) -> anyhow::Result<()> {
+    let pending = client.fetch_pending_assistance().await?;
+
+    let mut writer = csv::Writer::from_path(csv)?;
+    for item in &pending {
+        writer.serialize(PendingPayment {
+            req_id: item.request_id,
+            item_id: item.item_id,
+            neighbor: item.neighbor.clone(),
+            assistance_type: item.assistance_type.clone(),
+            amount: item.amount,
+            payee_name: item.payee_name.clone(),
+            promised_date: item.promised_date.clone(),
+            date_paid: None,
+            check_number: String::new(),
+        })?;
+    }
+    writer.flush()?;
+    tracing::info!("wrote {} pending items to {}", pending.len(), csv.display());
+
+    Ok(())
+}
+
+/// Marks every row of the pending CSV that has a check number as paid.
+pub async fn mark_paid(client: &impl ServWareApi, csv: &Path) -> anyhow::Result<()> {
+    let today = chrono::Local::now().format("%m/%d/%Y").to_string();
+
+    let mut reader = csv::Reader::from_path(csv)?;
+    for row in reader.deserialize() {
+        let row: PendingPayment = row?;
+        if row.check_number.trim().is_empty() {
+            continue;
+        }
+        let date_paid = row.date_paid.as_deref().unwrap_or(&today);
+
+        client
+            .mark_assistance_paid(row.req_id, row.item_id, date_paid, row.check_number.trim())
+            .await?;
+        tracing::info!(
+            "marked item {} of request {} paid by check {}",
+            row.item_id,
+            row.req_id,
+            row.check_number.trim()
+        );
+    }
+
+    Ok(())
+}
+
+/// Logs each row of the hours CSV as a mileage and hours entry.
+pub async fn log_hours(client: &impl ServWareApi, csv: &Path) -> anyhow::Result<()> {
+    let mut reader = csv::Reader::from_path(csv)?;
+    for row in reader.deserialize() {
+        let row: HoursRow = row?;
+        let date =
+            chrono::NaiveDate::parse_from_str(row.date.trim(), "%m/%d/%Y").with_context(|| {
+                format!("bad date {:?} for volunteer {}", row.date, row.volunteer_id)
+            })?;
+
+        let entry = NewServiceHours {
+            notes: row.notes,
+            ..NewServiceHours::new(
+                row.volunteer_id.trim(),
+                date,
+                row.hours,
+                row.miles.unwrap_or_default(),
+            )
+        };
+        client.create_service_hours(&entry).await?;
+        tracing::info!(
+            "logged {} hours and {} miles for volunteer {} on {}",
+            entry.hours_in_service,
+            entry.mileage_in_service,
+            entry.member_id,
+            entry.date_of_service
+        );
+    }
+
+    Ok(())
+}
+
+/// Prints the dashboard statistics, one figure per line.
+pub async fn print_statistics(client: &impl ServWareApi) -> anyhow::Result<()> {
+    for stat in client.fetch_statistics().await? {
+        println!("{:>8}  {}", stat.count, stat.count_description);
+    }
+
+    Ok(())
+}
+
+/// Writes the dashboard statistics to a (truncated) csv at the given path.
+pub async fn statistics_to_csv(client: &impl ServWareApi, csv: &Path) -> anyhow::Result<()> {
+    let mut writer = csv::Writer::from_path(csv)?;
+    for stat in client.fetch_statistics().await? {
+        writer.serialize(stat)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the dashboard statistics as a JSON array at the given path.
+pub async fn statistics_to_json(client: &impl ServWareApi, json: &Path) -> anyhow::Result<()> {
+    let stats = client.fetch_statistics().await?;
+    let file = std::fs::File::create(json)
+        .with_context(|| format!("failed to create {}", json.display()))?;
+    serde_json::to_writer_pretty(file, &stats)?;
+
+    Ok(())
+}
+
+/// Writes upcoming events from the given calendar feeds to an iCalendar
+/// file that phone calendars can import or subscribe to.
+///
+/// Covers today, from local midnight, through `days` days from now.
+pub async fn calendar_to_ics(
+    client: &impl ServWareApi,
+    ics: &Path,
+    feeds: &[CalendarFeed],
+    days: u32,
+) -> anyhow::Result<()> {
+    let now = Utc::now();
+    let start = chrono::Local::now()
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .and_then(|midnight| midnight.and_local_timezone(chrono::Local).earliest())
+        .map_or(now, |midnight| midnight.with_timezone(&Utc));
+    let end = now + chrono::Duration::days(days.into());
+
+    let mut calendar = String::new();
+    self::push_ics_line(&mut calendar, "BEGIN:VCALENDAR");
+    self::push_ics_line(&mut calendar, "VERSION:2.0");
+    self::push_ics_line(&mut calendar, "PRODID:-//SVdP Nativity//svdp//EN");
+    self::push_ics_line(&mut calendar, "CALSCALE:GREGORIAN");
+    self::push_ics_line(&mut calendar, "X-WR-CALNAME:ServWare");
+
+    let stamp = now.format(ICS_UTC_FORMAT).to_string();
+    for &feed in feeds {
+        let events = client.fetch_calendar(feed, start, end).await?;
+        tracing::info!("fetched {} {feed:?} events", events.len());
+
+        for event in events {
+            let uid = self::event_uid(feed, &event);
+            self::push_ics_line(&mut calendar, "BEGIN:VEVENT");
+            self::push_ics_line(&mut calendar, &format!("UID:{uid}"));
+            self::push_ics_line(&mut calendar, &format!("DTSTAMP:{stamp}"));
+            if event.all_day {
+                let date = event.start.format("%Y%m%d");
+                self::push_ics_line(&mut calendar, &format!("DTSTART;VALUE=DATE:{date}"));
+            } else {
+                let start = event.start.with_timezone(&Utc).format(ICS_UTC_FORMAT);
+                self::push_ics_line(&mut calendar, &format!("DTSTART:{start}"));
+                if let Some(end) = event.end {
+                    let end = end.with_timezone(&Utc).format(ICS_UTC_FORMAT);
+                    self::push_ics_line(&mut calendar, &format!("DTEND:{end}"));
+                }
+            }
+            let summary = self::escape_ics_text(&event.title);
+            self::push_ics_line(&mut calendar, &format!("SUMMARY:{summary}"));
+            if !event.url.is_empty() {
+                let url = format!("{}{}", client.base_url(), event.url);
+                self::push_ics_line(&mut calendar, &format!("URL:{url}"));
+            }
+            self::push_ics_line(&mut calendar, "END:VEVENT");
+        }
+    }
+    self::push_ics_line(&mut calendar, "END:VCALENDAR");
+
+    std::fs::write(ics, calendar).with_context(|| format!("failed to write {}", ics.display()))?;
+    Ok(())
+}
+
+/// A UID that stays the same across exports, so re-importing updates
+/// events instead of duplicating them. Built from the linked request when
+/// there is one, else from the start time and title.
+fn event_uid(feed: CalendarFeed, event: &CalendarEvent) -> String {
+    let start = event.start.timestamp();
+    match event.request_id {
+        Some(request_id) => format!("{feed:?}-{request_id}-{start}@servware.org"),
+        None => {
+            let title: String = event
+                .title
+                .chars()
+                .map(|c| if c.is_alphanumeric() { c } else { '-' })
+                .collect();
+            format!("{feed:?}-{start}-{}@servware.org", title.to_lowercase())
+        }
+    }
+}
+
+/// `"03/15/2026 call back; 04/01/2026 check on rent"`, or empty.
+fn follow_up_summary(neighbor: &Client) -> String {
+    neighbor
+        .open_follow_ups()
+        .map(|f| {
+            format!("{} {}", f.follow_up_date, f.notes)
+                .trim()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// `"Jane Doe: Approved; Bob Roe: Pending"`, or `"none"` for a request
+/// that needs no approval.
+fn approval_summary(approvals: &[Approval]) -> String {
+    if approvals.is_empty() {
+        return "none".to_string();
+    }
+    approvals
+        .iter()
+        .map(|a| format!("{}: {}", a.approver, a.status))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// ServWare's configured default value for an assistance type, or
+/// `fallback` when it has none configured or can't be reached.
+///
+/// The amount is read from the `itemvalue` response by
+/// [`ItemValue::from_json`], whose keys are guesses: none of them (nor the
+/// bare-number form) has been checked against a live ServWare response
+/// yet. So the value has to be a positive dollar amount, and an answer it
+/// can't read is an error rather than a silent fallback. Session errors
+/// are passed on too, so a run can't quietly record the fallback for every
+/// row after the session dies.
+async fn server_item_value(
+    client: &impl ServWareApi,
+    assistance_type_id: &str,
+    client_id: u64,
+    fallback: &str,
+) -> anyhow::Result<String> {
+    match client.fetch_item_value(assistance_type_id, client_id).await {
+        Ok(ItemValue {
+            monetary_value: Some(value),
+            ..
+        }) if value.is_finite() && value > 0.0 => Ok(value.to_string()),
+        Ok(ItemValue {
+            monetary_value: Some(value),
+            ..
+        }) => anyhow::bail!(
+            "ServWare suggested ${value} for assistance type {assistance_type_id} \
+             (neighbor {client_id}); fill in second_harvest_dollars instead"
+        ),
+        Ok(ItemValue { raw, .. }) if !raw.is_null() => anyhow::bail!(
+            "couldn't read an amount for assistance type {assistance_type_id} \
+             (neighbor {client_id}) from ServWare's answer {raw}"
+        ),
+        Ok(_) => {
+            tracing::warn!("no item value configured, using ${fallback}");
+            Ok(fallback.to_string())
+        }
+        Err(e) if e.is_session() => Err(e.into()),
+        Err(e) => {
+            tracing::warn!("failed to look up item value, using ${fallback}: {e:#}");
+            Ok(fallback.to_string())
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// iCalendar helpers
+// ---------------------------------------------------------------------------
+
+/// UTC date-time as iCalendar writes it, e.g. `20260203T080000Z`.
+const ICS_UTC_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// Append one content line, folded at 75 octets and CRLF-terminated as
+/// RFC 5545 requires.
+fn push_ics_line(calendar: &mut String, line: &str) {
+    let mut width = 0;
+    for ch in line.chars() {
+        if width + ch.len_utf8() > 75 {
+            calendar.push_str("\r\n ");
+            width = 1;
+        }
+        calendar.push(ch);
+        width += ch.len_utf8();
+    }
+    calendar.push_str("\r\n");
+}
+
+/// `text` safe to put inside the HTML of a rich-text field such as the
+/// request note.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn escape_ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CalendarEvent;
+    use super::CalendarFeed;
+    use super::escape_ics_text;
+    use super::event_uid;
+    use super::push_ics_line;
+
+    fn event(title: &str, start: &str, request_id: Option<u64>) -> CalendarEvent {
+        CalendarEvent {
+            title: title.into(),
+            start: chrono::DateTime::parse_from_rfc3339(start).unwrap(),
+            end: None,
+            url: String::new(),
+            request_id,
+            event_type: None,
+            color_code: None,
+            text_color_code: None,
+            all_day: false,
+            editable: false,
+        }
+    }
+
+    #[test]
+    fn uid_uses_the_request_id_when_there_is_one() {
+        let visit = event(
+            "Home Visit: Doe, Jane",
+            "2026-02-03T10:00:00-08:00",
+            Some(3724974),
+        );
+        assert_eq!(
+            event_uid(CalendarFeed::HomeVisits, &visit),
+            "HomeVisits-3724974-1770141600@servware.org"
+        );
+    }
+
+    #[test]
+    fn events_without_a_request_get_distinct_uids() {
+        let start = "2026-02-03T19:00:00-08:00";
+        let meeting = event("Conference Meeting", start, None);
+        let pantry = event("Pantry Shift", start, None);
+        let uid = event_uid(CalendarFeed::ConferenceEvents, &meeting);
+        assert_eq!(
+            uid,
+            "ConferenceEvents-1770174000-conference-meeting@servware.org"
+        );
+        assert_ne!(uid, event_uid(CalendarFeed::ConferenceEvents, &pantry));
+        assert_eq!(uid, event_uid(CalendarFeed::ConferenceEvents, &meeting));
+    }
+
+    #[test]
+    fn short_ics_lines_are_not_folded() {
+        let mut calendar = String::new();
+        push_ics_line(&mut calendar, "BEGIN:VCALENDAR");
+        assert_eq!(calendar, "BEGIN:VCALENDAR\r\n");
+    }
+
+    #[test]
+    fn long_ics_lines_fold_at_75_octets_between_characters() {
+        let line = format!("SUMMARY:{}", "Visita a la señora Núñez — ".repeat(4));
+        let mut calendar = String::new();
+        push_ics_line(&mut calendar, &line);
+
+        let physical: Vec<&str> = calendar.trim_end_matches("\r\n").split("\r\n").collect();
+        assert!(physical.len() > 1);
+        for (i, part) in physical.iter().enumerate() {
+            assert!(part.len() <= 75, "line {i} is {} octets", part.len());
+            assert_eq!(i > 0, part.starts_with(' '));
+        }
+        assert_eq!(calendar.replace("\r\n ", ""), format!("{line}\r\n"));
+    }
+
+    #[test]
+    fn exactly_75_octets_fits_on_one_line() {
+        let line = format!("SUMMARY:{}", "é".repeat(33)) + "x";
+        assert_eq!(line.len(), 75);
+        let mut calendar = String::new();
+        push_ics_line(&mut calendar, &line);
+        assert_eq!(calendar, format!("{line}\r\n"));
+    }
+
+    #[test]
+    fn ics_text_is_escaped() {
+        assert_eq!(
+            escape_ics_text("Lopez, Ana; call\nback \\ soon"),
+            r"Lopez\, Ana\; call\nback \\ soon"
+        );
+    }
+}
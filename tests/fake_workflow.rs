@@ -0,0 +1,358 @@
+use futures_util::TryStreamExt;
+use svdp::api::ServWareApi;
+use svdp::api::fake::FakeServWare;
+use svdp::api::fetch_activity_report::ReportRow;
+use svdp::api::fetch_calendar::CalendarEvent;
+use svdp::api::fetch_calendar::CalendarFeed;
+use svdp::api::fetch_lookups::LookupOption;
+use svdp::api::fetch_lookups::Lookups;
+use svdp::api::fetch_members::Member;
+use svdp::api::fetch_requests::AssistanceRequest;
+use svdp::api::fetch_requests::Client;
+use svdp::api::fetch_requests::FetchRequestsParams;
+use svdp::api::recording::Call;
+use svdp::api::recording::Recording;
+use svdp::api::update_assistance::UpdateAssistanceInput;
+use svdp::nativity;
+
+fn request(id: u64, client_id: u64, status: &str, household: u32) -> AssistanceRequest {
+    AssistanceRequest {
+        id,
+        status: status.into(),
+        calculated_household_count: household,
+        client: Client {
+            id: client_id,
+            first_name: format!("First{id}"),
+            last_name: format!("Last{id}"),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn fake() -> Recording<FakeServWare> {
+    Recording::new(FakeServWare::new(
+        vec![Member {
+            id: "44270".into(),
+            name: "Jane Volunteer".into(),
+        }],
+        vec![
+            request(1, 11, "Open", 2),
+            request(2, 12, "Completed", 3),
+            request(3, 13, "Open", 6),
+        ],
+    ))
+}
+
+#[tokio::test]
+async fn workflow_runs_against_fake() -> anyhow::Result<()> {
+    let api = fake();
+    let dir = tempfile::tempdir()?;
+    let csv = dir.path().join("requests.csv");
+
+    nativity::requests_to_csv(&api, &csv, false).await?;
+    nativity::update_complete(&api, &csv, "44270").await?;
+    nativity::add_assistance(&api, &csv, false).await?;
+
+    let fake = api.inner();
+    assert_eq!(fake.request(1).unwrap().status, "Completed");
+    assert_eq!(fake.request(3).unwrap().status, "Completed");
+    assert!(fake.request(2).unwrap().assistance_items.is_empty());
+
+    let gift_cards: Vec<f64> = fake
+        .request(3)
+        .unwrap()
+        .assistance_items
+        .iter()
+        .map(|i| i.monetary_value)
+        .collect();
+    assert_eq!(gift_cards, [70.0, 100.0]);
+
+    let updated: Vec<u64> = api
+        .calls()
+        .iter()
+        .filter_map(|c| match c {
+            Call::UpdateRequest { request_id, input } => {
+                assert_eq!(
+                    input.request_assigned_to_member_id.as_deref(),
+                    Some("44270")
+                );
+                Some(*request_id)
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(updated, [1, 3]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn csv_value_overrides_server_default() -> anyhow::Result<()> {
+    let fake = FakeServWare::new(Vec::new(), vec![request(1, 11, "Open", 2)])
+        .with_item_value("16542", 65.0);
+    let api = Recording::new(fake);
+    let dir = tempfile::tempdir()?;
+    let csv = dir.path().join("requests.csv");
+
+    nativity::requests_to_csv(&api, &csv, false).await?;
+    nativity::add_assistance(&api, &csv, true).await?;
+
+    let mut rows: Vec<nativity::OpenRequest> = csv::Reader::from_path(&csv)?
+        .deserialize()
+        .collect::<Result<_, _>>()?;
+    rows[0].second_harvest_dollars = Some(40);
+    let mut writer = csv::Writer::from_path(&csv)?;
+    writer.serialize(&rows[0])?;
+    writer.flush()?;
+    nativity::add_assistance(&api, &csv, true).await?;
+
+    let values: Vec<f64> = api
+        .inner()
+        .request(1)
+        .unwrap()
+        .assistance_items
+        .iter()
+        .map(|i| i.monetary_value)
+        .collect();
+    assert_eq!(values, [65.0, 60.0, 40.0, 60.0]);
+
+    let lookups = api
+        .calls()
+        .iter()
+        .filter(|c| matches!(c, Call::FetchItemValue { .. }))
+        .count();
+    assert_eq!(lookups, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn server_value_must_be_a_positive_amount() -> anyhow::Result<()> {
+    for value in [0.0, -5.0] {
+        let fake = FakeServWare::new(Vec::new(), vec![request(1, 11, "Open", 2)])
+            .with_item_value("16542", value);
+        let api = Recording::new(fake);
+        let dir = tempfile::tempdir()?;
+        let csv = dir.path().join("requests.csv");
+        nativity::requests_to_csv(&api, &csv, false).await?;
+
+        let err = nativity::add_assistance(&api, &csv, true)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("second_harvest_dollars"), "{err}");
+        assert!(api.inner().request(1).unwrap().assistance_items.is_empty());
+
+        // Without asking ServWare, the hard-coded value is used.
+        nativity::add_assistance(&api, &csv, false).await?;
+        let item = &api.inner().request(1).unwrap().assistance_items[0];
+        assert_eq!(item.monetary_value, 70.0);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn stream_walks_every_page() -> anyhow::Result<()> {
+    let fake = FakeServWare::new(
+        Vec::new(),
+        (1..=7).map(|id| request(id, id + 10, "Open", 1)).collect(),
+    );
+    let api = Recording::new(fake);
+
+    let params = FetchRequestsParams {
+        display_length: 3,
+        ..FetchRequestsParams::new_open_asc()
+    };
+    let ids: Vec<u64> = api
+        .stream_requests(params)
+        .map_ok(|r| r.id)
+        .try_collect()
+        .await?;
+
+    assert_eq!(ids, (1..=7).collect::<Vec<_>>());
+    assert_eq!(api.calls().len(), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn intake_and_contact_fixes_run_against_fake() -> anyhow::Result<()> {
+    let api = fake();
+    let dir = tempfile::tempdir()?;
+    let intake = dir.path().join("intake.csv");
+    std::fs::write(
+        &intake,
+        "neighbor_id,first_name,last_name,home_phone,street_address_line1,city,date_requested,request_note\n\
+         12,,,,,,03/01/2026,Rice & beans <asap>\n\
+         ,Eve,Newton,(650) 555-0123,7 Pine St,Menlo Park,03/02/2026,\n",
+    )?;
+    nativity::intake(&api, &intake).await?;
+
+    let fake = api.inner();
+    let opened = fake.request(4).unwrap();
+    assert_eq!(opened.client.id, 12);
+    assert_eq!(opened.status, "Open");
+    assert_eq!(opened.request_note, "<p>Rice &amp; beans &lt;asap&gt;</p>");
+    let walk_in = fake.request(5).unwrap();
+    assert_eq!(walk_in.client.id, 14);
+    assert_eq!(walk_in.client.last_name, "Newton");
+    assert_eq!(walk_in.date_requested, "03/02/2026");
+
+    let contacts = dir.path().join("contacts.csv");
+    std::fs::write(
+        &contacts,
+        "neighbor_id,home_phone,mobile_phone,email_address,street_address_line1,street_address_line2,city,postal_code\n\
+         14,,(650) 555-0199,,,,,\n\
+         99,,(650) 555-0100,,,,,\n",
+    )?;
+    nativity::update_contacts(&api, &contacts).await?;
+    assert_eq!(
+        fake.request(5).unwrap().client.mobile_phone,
+        "(650) 555-0199"
+    );
+
+    let created = api
+        .calls()
+        .iter()
+        .filter(|c| matches!(c, Call::CreateClient(_)))
+        .count();
+    assert_eq!(created, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn treasurer_and_hours_run_against_fake() -> anyhow::Result<()> {
+    let report = vec![ReportRow {
+        section: "Assistance".into(),
+        description: "Food".into(),
+        count: Some(2),
+        amount: Some(140.0),
+    }];
+    let api = Recording::new(
+        FakeServWare::new(Vec::new(), vec![request(1, 11, "Open", 2)])
+            .with_activity_report(report.clone()),
+    );
+    let rent = UpdateAssistanceInput {
+        payee_name: "Acme Apartments".into(),
+        pending: true,
+        ..UpdateAssistanceInput::new("16530", "11", "1250", "1", "03/02/2026")
+    };
+    api.update_assistance(1, &rent).await?;
+
+    let dir = tempfile::tempdir()?;
+    let csv = dir.path().join("pending.csv");
+    nativity::pending_to_csv(&api, &csv).await?;
+    let mut rows: Vec<nativity::PendingPayment> = csv::Reader::from_path(&csv)?
+        .deserialize()
+        .collect::<Result<_, _>>()?;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].neighbor, "Last1, First1");
+    rows[0].check_number = "1042".into();
+    let mut writer = csv::Writer::from_path(&csv)?;
+    writer.serialize(&rows[0])?;
+    writer.flush()?;
+    nativity::mark_paid(&api, &csv).await?;
+
+    let item = &api.inner().request(1).unwrap().assistance_items[0];
+    assert!(!item.pending);
+    assert_eq!(item.check_number, "1042");
+    assert!(api.fetch_pending_assistance().await?.is_empty());
+
+    let hours = dir.path().join("hours.csv");
+    std::fs::write(
+        &hours,
+        "volunteer_id,date,hours,miles,notes\n\
+         44270,03/04/2026,2.5,12,Pantry shift\n",
+    )?;
+    nativity::log_hours(&api, &hours).await?;
+    let logged = api.inner().service_hours();
+    assert_eq!(logged.len(), 1);
+    assert_eq!(logged[0].date_of_service, "03/04/2026");
+    assert_eq!(logged[0].mileage_in_service, "12");
+
+    let report_csv = dir.path().join("report.csv");
+    let day = chrono::NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+    nativity::activity_report_to_csv(&api, day, day, &report_csv).await?;
+    let written: Vec<ReportRow> = csv::Reader::from_path(&report_csv)?
+        .deserialize()
+        .collect::<Result<_, _>>()?;
+    assert_eq!(written, report);
+    Ok(())
+}
+
+#[tokio::test]
+async fn lookups_follow_ups_and_calendar_run_against_fake() -> anyhow::Result<()> {
+    let lookups = Lookups {
+        assistance_types: vec![LookupOption {
+            id: "16530".into(),
+            name: "Rent".into(),
+        }],
+        ..Default::default()
+    };
+    let visit = CalendarEvent {
+        title: "Home Visit: Last1, First1".into(),
+        start: chrono::Local::now().fixed_offset() + chrono::Duration::hours(1),
+        end: None,
+        url: "/app/assistancerequests/1".into(),
+        request_id: Some(1),
+        event_type: None,
+        color_code: None,
+        text_color_code: None,
+        all_day: false,
+        editable: false,
+    };
+    let api = Recording::new(
+        FakeServWare::new(Vec::new(), vec![request(1, 11, "Open", 2)])
+            .with_lookups(lookups)
+            .with_calendar_events(CalendarFeed::HomeVisits, vec![visit]),
+    );
+    let dir = tempfile::tempdir()?;
+
+    nativity::lookups_to_csv(&api, dir.path()).await?;
+    let types: Vec<LookupOption> = csv::Reader::from_path(dir.path().join("assistance_types.csv"))?
+        .deserialize()
+        .collect::<Result<_, _>>()?;
+    assert_eq!(types.len(), 1);
+    assert_eq!(types[0].id, "16530");
+
+    nativity::print_clients(&api, "last1").await?;
+    assert_eq!(api.search_clients("LAST1").await?.len(), 1);
+    assert!(api.search_clients("nobody").await?.is_empty());
+
+    nativity::add_follow_up(&api, 11, 7, "call back").await?;
+    nativity::print_follow_ups(&api, Some(7)).await?;
+    assert!(
+        nativity::add_follow_up(&api, 99, 7, "unknown")
+            .await
+            .is_err()
+    );
+    let open = api.open_follow_ups().await?;
+    assert_eq!(open.len(), 1);
+    assert_eq!(open[0].notes, "call back");
+    assert_eq!(open[0].client.as_ref().map(|c| c.id), Some(11));
+    let today = chrono::Local::now().date_naive();
+    assert!(api.due_follow_ups(today).await?.is_empty());
+
+    let ics = dir.path().join("calendar.ics");
+    nativity::calendar_to_ics(&api, &ics, &[CalendarFeed::HomeVisits], 7).await?;
+    let calendar = std::fs::read_to_string(&ics)?;
+    assert!(calendar.contains("SUMMARY:Home Visit: Last1\\, First1"));
+    assert!(calendar.contains("URL:https://servware.invalid/app/assistancerequests/1"));
+
+    let calls = api.calls();
+    assert!(
+        calls
+            .iter()
+            .any(|c| matches!(c, Call::FetchLookups { request_id: 1 }))
+    );
+    assert!(
+        calls
+            .iter()
+            .any(|c| matches!(c, Call::CreateFollowUp { client_id: 11, .. }))
+    );
+    assert!(calls.iter().any(|c| matches!(
+        c,
+        Call::FetchCalendar {
+            feed: CalendarFeed::HomeVisits,
+            ..
+        }
+    )));
+    Ok(())
+}
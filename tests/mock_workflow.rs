@@ -0,0 +1,926 @@
+use std::time::Duration;
+
+use futures_util::TryStreamExt;
+use secrecy::SecretString;
+use svdp::api::FieldError;
+use svdp::api::ServWare;
+use svdp::api::ServWareApi;
+use svdp::api::ServWareError;
+use svdp::api::fetch_approvals::Approval;
+use svdp::api::fetch_calendar::CalendarFeed;
+use svdp::api::fetch_client_detail::ClientDetail;
+use svdp::api::fetch_clients::FetchClientsParams;
+use svdp::api::fetch_lookups::LookupOption;
+use svdp::api::fetch_request_detail::RequestDetail;
+use svdp::api::follow_ups::NewFollowUp;
+use svdp::api::service_hours::NewServiceHours;
+use svdp::api::session_file::SessionFile;
+use svdp::api::update_assistance::UpdateAssistanceInput;
+use svdp::api::update_client::UpdateClientInput;
+use svdp::api::update_request::UpdateRequestInput;
+use svdp::mock::MockClient;
+use svdp::mock::MockRequest;
+use svdp::mock::MockServer;
+use svdp::mock::MockState;
+use svdp::nativity;
+
+const USER: &str = "volunteer";
+const PASS: &str = "hunter2";
+
+fn seeded() -> MockState {
+    MockState::new(USER, PASS)
+        .with_member("44270", "Jane Volunteer")
+        .with_request(MockRequest {
+            household_count: 4,
+            ..MockRequest::open(101, 9001, "Ana", "Lopez")
+        })
+        .with_request(MockRequest::open(102, 9002, "Ben", "Ng"))
+        .with_request(MockRequest {
+            status: "Completed".into(),
+            ..MockRequest::open(103, 9003, "Cy", "Old")
+        })
+}
+
+async fn login(server: &MockServer) -> svdp::api::Result<ServWare> {
+    ServWare::new_session(&server.base_url(), USER, &SecretString::from(PASS)).await
+}
+
+#[tokio::test]
+async fn bad_password_is_rejected() {
+    let server = MockServer::start(seeded()).await.unwrap();
+    let result =
+        ServWare::new_session(&server.base_url(), USER, &SecretString::from("wrong")).await;
+    assert!(matches!(result, Err(ServWareError::BadCredentials)));
+}
+
+#[tokio::test]
+async fn weekly_workflow_against_mock() -> anyhow::Result<()> {
+    let server = MockServer::start(seeded()).await?;
+    let client = login(&server).await?;
+    let dir = tempfile::tempdir()?;
+    let requests = dir.path().join("requests.csv");
+    let members = dir.path().join("volunteers.csv");
+
+    nativity::members_to_csv(&client, &members).await?;
+    assert!(std::fs::read_to_string(&members)?.contains("44270,Jane Volunteer"));
+
+    nativity::requests_to_csv(&client, &requests, false).await?;
+    let exported: Vec<nativity::OpenRequest> = csv::Reader::from_path(&requests)?
+        .deserialize()
+        .collect::<Result<_, _>>()?;
+    let ids: Vec<u64> = exported.iter().map(|r| r.req_id).collect();
+    assert_eq!(ids, [101, 102]);
+    assert_eq!(exported[0].gift_card_dollars, 80);
+
+    nativity::update_complete(&client, &requests, "44270").await?;
+    {
+        let state = server.state();
+        assert_eq!(state.request_status(101), Some("Completed"));
+        assert_eq!(state.request_status(102), Some("Completed"));
+        assert_eq!(state.request_updates.len(), 2);
+        let form = &state.request_updates[0];
+        assert_eq!(form.get("requestAssignedToMemberId"), Some("44270"));
+        assert_eq!(form.get("visitCompleted"), Some("true"));
+    }
+
+    nativity::add_assistance(&client, &requests, false).await?;
+    let state = server.state();
+    assert_eq!(state.assistance_items.len(), 4);
+    let gift_card = &state.assistance_items[1];
+    assert_eq!(gift_card.id, 101);
+    assert_eq!(gift_card.get("monetaryValue"), Some("80"));
+    assert_eq!(gift_card.get("clientId"), Some("9001"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn completed_request_can_be_corrected() -> anyhow::Result<()> {
+    let server = MockServer::start(seeded()).await?;
+    let client = login(&server).await?;
+
+    let request = client.fetch_request(103).await?;
+    assert_eq!(request.status, "Completed");
+    assert!(client.fetch_request(10).await.unwrap_err().is_not_found());
+
+    let input = UpdateRequestInput {
+        visit_notes: Some("Corrected after the fact".into()),
+        ..Default::default()
+    };
+    client.update_request(103, &input).await?;
+    let state = server.state();
+    assert_eq!(state.request_updates.len(), 1);
+    assert_eq!(
+        state.request_updates[0].get("visitNotes"),
+        Some("Corrected after the fact")
+    );
+    assert_eq!(state.request_status(103), Some("Completed"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn update_keeps_fields_it_does_not_touch() -> anyhow::Result<()> {
+    let server = MockServer::start(seeded()).await?;
+    let client = login(&server).await?;
+
+    let assign = UpdateRequestInput {
+        request_assigned_to_member_id: Some("44270".into()),
+        visit_assigned_to_member_id: Some("44270".into()),
+        referred_from_organization_id: Some("310".into()),
+        request_note: Some("<p>Rent & utilities</p>".into()),
+        prison_visit: Some(true),
+        ..Default::default()
+    };
+    client.update_request(101, &assign).await?;
+
+    let detail = client.fetch_request_detail(101).await?;
+    assert_eq!(detail.request_assigned_to_member_id, "44270");
+    assert_eq!(detail.visit_assigned_to_member_id, "44270");
+    assert_eq!(detail.visit_assigned_to_member_id_secondary, "");
+    assert_eq!(detail.referred_from_organization_id, "310");
+    assert_eq!(detail.request_note, "<p>Rent & utilities</p>");
+    assert_eq!(detail.client_first_name, "Ana");
+    assert!(detail.prison_visit);
+    assert!(!detail.home_visit_required);
+
+    let notes = UpdateRequestInput {
+        visit_notes: Some("Dropped off food".into()),
+        ..Default::default()
+    };
+    client.update_request(101, &notes).await?;
+    let state = server.state();
+    let form = &state.request_updates[1];
+    assert_eq!(form.get("requestAssignedToMemberId"), Some("44270"));
+    assert_eq!(form.get("referredFromOrganizationId"), Some("310"));
+    assert_eq!(form.get("prisonVisit"), Some("true"));
+    assert_eq!(form.get("visitNotes"), Some("Dropped off food"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn statistics_are_exported() -> anyhow::Result<()> {
+    let server = MockServer::start(seeded()).await?;
+    let client = login(&server).await?;
+
+    let stats = client.fetch_statistics().await?;
+    let count = |description: &str| {
+        stats
+            .iter()
+            .find(|s| s.count_description == description)
+            .map(|s| s.count)
+    };
+    assert_eq!(count("Open Requests (FY)"), Some(2));
+    assert_eq!(count("Completed Requests (FY)"), Some(1));
+    assert_eq!(count("Total Neighbors"), Some(3));
+
+    let dir = tempfile::tempdir()?;
+    let csv = dir.path().join("stats.csv");
+    nativity::statistics_to_csv(&client, &csv).await?;
+    assert!(std::fs::read_to_string(&csv)?.starts_with("count,countDescription\n"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn home_visits_export_to_ics() -> anyhow::Result<()> {
+    let server = MockServer::start(seeded()).await?;
+    let client = login(&server).await?;
+    let tomorrow = chrono::Local::now().date_naive() + chrono::Days::new(1);
+
+    let schedule = UpdateRequestInput {
+        visit_scheduled_date: Some(tomorrow.format("%m/%d/%Y").to_string()),
+        ..Default::default()
+    };
+    client.update_request(101, &schedule).await?;
+
+    let now = chrono::Utc::now();
+    let visits = client
+        .fetch_home_visits(now - chrono::Days::new(2), now + chrono::Days::new(7))
+        .await?;
+    assert_eq!(visits.len(), 1);
+    assert_eq!(visits[0].request_id, Some(101));
+    assert_eq!(visits[0].title, "Home Visit: Lopez, Ana");
+
+    let dir = tempfile::tempdir()?;
+    let ics = dir.path().join("visits.ics");
+    nativity::calendar_to_ics(&client, &ics, &[CalendarFeed::HomeVisits], 7).await?;
+    let ics = std::fs::read_to_string(&ics)?;
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.contains("SUMMARY:Home Visit: Lopez\\, Ana\r\n"));
+    assert!(ics.contains(&format!(
+        "URL:{}/app/assistancerequests/101\r\n",
+        server.base_url()
+    )));
+    assert_eq!(ics.matches("BEGIN:VEVENT").count(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn assistance_uses_server_item_value() -> anyhow::Result<()> {
+    let mut state = seeded();
+    state.item_values.insert("16542".into(), 65.0);
+    let server = MockServer::start(state).await?;
+    let client = login(&server).await?;
+
+    let value = client.fetch_item_value("16542", 9001).await?;
+    assert_eq!(value.monetary_value, Some(65.0));
+    let unset = client.fetch_item_value("16522", 9001).await?;
+    assert_eq!(unset.monetary_value, None);
+
+    let dir = tempfile::tempdir()?;
+    let requests = dir.path().join("requests.csv");
+    nativity::requests_to_csv(&client, &requests, false).await?;
+    nativity::add_assistance(&client, &requests, true).await?;
+
+    let state = server.state();
+    let second_harvest = &state.assistance_items[0];
+    assert_eq!(second_harvest.get("assistanceTypeId"), Some("16542"));
+    assert_eq!(second_harvest.get("monetaryValue"), Some("65"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn lookups_are_scraped_from_dropdowns() -> anyhow::Result<()> {
+    let option = |id: &str, name: &str| LookupOption {
+        id: id.into(),
+        name: name.into(),
+    };
+    let mut state = seeded();
+    state.assistance_types = vec![
+        option("16542", "Second Harvest Food"),
+        option("16522", "Gift Cards"),
+    ];
+    state.denial_reasons = vec![option("7", "Outside boundaries")];
+    let server = MockServer::start(state).await?;
+    let client = login(&server).await?;
+
+    let lookups = client.fetch_lookups(101).await?;
+    let types: Vec<&str> = lookups
+        .assistance_types
+        .iter()
+        .map(|o| o.name.as_str())
+        .collect();
+    assert_eq!(types, ["Second Harvest Food", "Gift Cards"]);
+    assert_eq!(lookups.denial_reasons[0].id, "7");
+    assert!(lookups.referral_organizations.is_empty());
+
+    let dir = tempfile::tempdir()?;
+    nativity::lookups_to_csv(&client, dir.path()).await?;
+    let written = std::fs::read_to_string(dir.path().join("assistance_types.csv"))?;
+    assert_eq!(
+        written,
+        "id,name\n16542,Second Harvest Food\n16522,Gift Cards\n"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachments_round_trip() -> anyhow::Result<()> {
+    let server = MockServer::start(seeded()).await?;
+    let client = login(&server).await?;
+    assert!(client.fetch_attachments(101).await?.is_empty());
+
+    let receipt = b"signed receipt".to_vec();
+    client
+        .upload_attachment(101, "receipt.txt", receipt.clone())
+        .await?;
+
+    let attachments = client.fetch_attachments(101).await?;
+    assert_eq!(attachments.len(), 1);
+    assert_eq!(attachments[0].name, "receipt.txt");
+    assert_eq!(client.download_attachment(&attachments[0]).await?, receipt);
+    assert!(client.fetch_attachments(102).await?.is_empty());
+
+    let state = server.state();
+    assert_eq!(state.files[0].content_type, "text/plain");
+    let form = &state.request_updates[0];
+    assert_eq!(form.get("status"), Some("Open"));
+    assert_eq!(form.get("clientFirstName"), Some("Ana"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn approvals_are_parsed_and_exported() -> anyhow::Result<()> {
+    let mut state = seeded();
+    state.approvals.insert(
+        101,
+        vec![
+            Approval {
+                approver: "Jane Volunteer".into(),
+                status: "Approved".into(),
+                date: "03/02/2025".into(),
+                notes: "Rent & utilities".into(),
+            },
+            Approval {
+                approver: "Bob Treasurer".into(),
+                status: "Pending".into(),
+                ..Default::default()
+            },
+        ],
+    );
+    let server = MockServer::start(state).await?;
+    let client = login(&server).await?;
+
+    let approvals = client.fetch_approvals(101).await?;
+    assert_eq!(approvals.len(), 2);
+    assert!(approvals[0].is_approved());
+    assert_eq!(approvals[0].notes, "Rent & utilities");
+    assert!(!approvals[1].is_approved());
+    assert!(client.fetch_approvals(102).await?.is_empty());
+
+    let dir = tempfile::tempdir()?;
+    let requests = dir.path().join("requests.csv");
+    nativity::requests_to_csv(&client, &requests, true).await?;
+    let exported: Vec<nativity::OpenRequest> = csv::Reader::from_path(&requests)?
+        .deserialize()
+        .collect::<Result<_, _>>()?;
+    assert_eq!(
+        exported[0].req_approvals,
+        "Jane Volunteer: Approved; Bob Treasurer: Pending"
+    );
+    assert_eq!(exported[1].req_approvals, "none");
+    Ok(())
+}
+
+#[tokio::test]
+async fn clients_are_searchable() -> anyhow::Result<()> {
+    let state = seeded().with_client(MockClient {
+        home_phone: "(650) 555-0100".into(),
+        street_address: "42 Oak Ave".into(),
+        ..MockClient::new(9100, "Dee", "Walkin")
+    });
+    let server = MockServer::start(state).await?;
+    let client = login(&server).await?;
+
+    let found = client.search_clients("walkin").await?;
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].id, 9100);
+    assert_eq!(found[0].first_name, "Dee");
+    assert_eq!(client.search_clients("650-555-0100").await?[0].id, 9100);
+    assert_eq!(client.search_clients("42 oak").await?[0].id, 9100);
+    assert!(client.search_clients("nobody").await?.is_empty());
+
+    // Every neighbor, seeded or behind a request, across small pages.
+    let all: Vec<_> = client
+        .stream_clients(FetchClientsParams {
+            display_length: 2,
+            ..FetchClientsParams::new_all()
+        })
+        .try_collect()
+        .await?;
+    let ids: Vec<u64> = all.iter().map(|c| c.id).collect();
+    assert_eq!(ids, [9001, 9002, 9003, 9100]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn contact_corrections_keep_other_fields() -> anyhow::Result<()> {
+    let mut state = seeded();
+    if let Some(neighbor) = state.clients.get_mut(&9001) {
+        neighbor["householdIncome"] = serde_json::json!("1200");
+    }
+    let server = MockServer::start(state).await?;
+    let client = login(&server).await?;
+    let dir = tempfile::tempdir()?;
+    let contacts = dir.path().join("contacts.csv");
+    std::fs::write(
+        &contacts,
+        "neighbor_id,home_phone,mobile_phone,email_address,street_address_line1,street_address_line2,city,postal_code\n\
+         4040,(650) 555-0404,,,,,,\n\
+         9001,(650) 555-0199,,,9 Elm St,,,\n",
+    )?;
+
+    // The unknown neighbor is skipped rather than failing the batch.
+    nativity::update_contacts(&client, &contacts).await?;
+
+    {
+        let state = server.state();
+        assert_eq!(state.client_updates.len(), 1);
+        let form = &state.client_updates[0];
+        assert_eq!(form.id, 9001);
+        assert_eq!(form.get("homePhone"), Some("(650) 555-0199"));
+        assert_eq!(form.get("streetAddressLine1"), Some("9 Elm St"));
+        assert_eq!(form.get("firstName"), Some("Ana"));
+        assert_eq!(form.get("city"), Some("Menlo Park"));
+        assert_eq!(form.get("householdIncome"), Some("1200"));
+        assert_eq!(form.get("_parishioner"), Some("on"));
+        assert_eq!(form.get("parishioner"), None);
+    }
+
+    let unnamed = UpdateClientInput {
+        last_name: Some(String::new()),
+        ..Default::default()
+    };
+    let err = client.update_client(9002, &unnamed).await.unwrap_err();
+    assert!(matches!(err, ServWareError::Validation { .. }));
+    assert_eq!(err.field_errors()[0].field.as_deref(), Some("lastName"));
+    assert_eq!(server.state().rejected_forms.len(), 1);
+
+    let detail = client.fetch_client_detail(9001).await?;
+    assert_eq!(detail.home_phone, "(650) 555-0199");
+    assert_eq!(detail.last_name, "Lopez");
+    let found = client.search_clients("650-555-0199").await?;
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].street_address_line1, "9 Elm St");
+    Ok(())
+}
+
+#[tokio::test]
+async fn intake_opens_requests_and_adds_new_neighbors() -> anyhow::Result<()> {
+    let server = MockServer::start(seeded()).await?;
+    let client = login(&server).await?;
+    let dir = tempfile::tempdir()?;
+    let intake = dir.path().join("intake.csv");
+    std::fs::write(
+        &intake,
+        "neighbor_id,first_name,last_name,home_phone,street_address_line1,city,date_requested,request_note\n\
+         9002,,,,,,03/01/2026,Needs groceries\n\
+         ,Eve,Newton,(650) 555-0123,7 Pine St,Menlo Park,,\n",
+    )?;
+
+    nativity::intake(&client, &intake).await?;
+
+    {
+        let state = server.state();
+        assert_eq!(state.created_clients.len(), 1);
+        let new_client = &state.created_clients[0];
+        assert_eq!(new_client.id, 9004);
+        assert_eq!(new_client.get("lastName"), Some("Newton"));
+        assert_eq!(new_client.get("homePhone"), Some("(650) 555-0123"));
+
+        assert_eq!(state.created_requests.len(), 2);
+        let existing = &state.created_requests[0];
+        assert_eq!(existing.get("clientId"), Some("9002"));
+        assert_eq!(existing.get("clientFirstName"), Some("Ben"));
+        assert_eq!(existing.get("status"), Some("Open"));
+        assert_eq!(existing.get("requestNote"), Some("<p>Needs groceries</p>"));
+        assert_eq!(state.created_requests[1].get("clientId"), Some("9004"));
+    }
+
+    let opened = client.fetch_request(104).await?;
+    assert_eq!(opened.status, "Open");
+    assert_eq!(opened.date_requested, "03/01/2026");
+    assert_eq!(opened.client.id, 9002);
+    let walk_in = client.fetch_request(105).await?;
+    assert_eq!(walk_in.client.first_name, "Eve");
+    assert_eq!(client.search_clients("newton").await?[0].id, 9004);
+
+    let err = client
+        .create_client(&ClientDetail::default())
+        .await
+        .unwrap_err();
+    assert_eq!(err.field_errors()[0].field.as_deref(), Some("lastName"));
+    let undated = RequestDetail {
+        status: "Open".into(),
+        ..Default::default()
+    };
+    let err = client.create_request(9002, &undated).await.unwrap_err();
+    assert!(matches!(err, ServWareError::Validation { .. }));
+    assert_eq!(
+        err.field_errors()[0].field.as_deref(),
+        Some("dateRequested")
+    );
+    assert_eq!(server.state().rejected_forms.len(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn follow_ups_are_listed_created_and_exported() -> anyhow::Result<()> {
+    let state = seeded()
+        .with_follow_up(9001, "01/15/2026", "Call about rent")
+        .with_follow_up(9002, "12/31/2099", "Annual check-in");
+    let server = MockServer::start(state).await?;
+    let client = login(&server).await?;
+
+    let open = client.open_follow_ups().await?;
+    let notes: Vec<&str> = open.iter().map(|f| f.notes.as_str()).collect();
+    assert_eq!(notes, ["Call about rent", "Annual check-in"]);
+
+    let by = chrono::NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+    let due = client.due_follow_ups(by).await?;
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].client.as_ref().map(|c| c.id), Some(9001));
+
+    let call_back = chrono::NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+    client
+        .create_follow_up(9003, &NewFollowUp::new(call_back, "Call back"))
+        .await?;
+    {
+        let state = server.state();
+        let form = &state.created_follow_ups[0];
+        assert_eq!(form.id, 9003);
+        assert_eq!(form.get("followUpDate"), Some("03/01/2026"));
+        assert_eq!(form.get("notes"), Some("Call back"));
+    }
+    assert_eq!(client.due_follow_ups(by).await?.len(), 2);
+
+    let undated = NewFollowUp {
+        follow_up_date: String::new(),
+        ..NewFollowUp::new(call_back, "No date")
+    };
+    let err = client.create_follow_up(9003, &undated).await.unwrap_err();
+    assert_eq!(
+        err.field_errors(),
+        [FieldError {
+            field: Some("followUpDate".into()),
+            message: "Follow-up date is required.".into(),
+        }]
+    );
+    assert_eq!(server.state().rejected_forms[0].id, 9003);
+    assert_eq!(client.due_follow_ups(by).await?.len(), 2);
+
+    let dir = tempfile::tempdir()?;
+    let requests = dir.path().join("requests.csv");
+    nativity::requests_to_csv(&client, &requests, false).await?;
+    let exported: Vec<nativity::OpenRequest> = csv::Reader::from_path(&requests)?
+        .deserialize()
+        .collect::<Result<_, _>>()?;
+    assert_eq!(
+        exported[0].neighbor_follow_ups,
+        "01/15/2026 Call about rent"
+    );
+    assert_eq!(
+        exported[1].neighbor_follow_ups,
+        "12/31/2099 Annual check-in"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn malformed_follow_up_does_not_break_the_request_list() -> anyhow::Result<()> {
+    let mut state = seeded().with_follow_up(9001, "01/15/2026", "Call about rent");
+    state.follow_ups.insert(
+        99,
+        serde_json::json!({
+            "id": 99,
+            "clientId": 9002,
+            "followUpDate": 20260301,
+            "completed": "no",
+        }),
+    );
+    let server = MockServer::start(state).await?;
+    let client = login(&server).await?;
+
+    let dir = tempfile::tempdir()?;
+    let requests = dir.path().join("requests.csv");
+    nativity::requests_to_csv(&client, &requests, false).await?;
+    let exported: Vec<nativity::OpenRequest> = csv::Reader::from_path(&requests)?
+        .deserialize()
+        .collect::<Result<_, _>>()?;
+    let ids: Vec<u64> = exported.iter().map(|r| r.req_id).collect();
+    assert_eq!(ids, [101, 102]);
+    assert_eq!(
+        exported[0].neighbor_follow_ups,
+        "01/15/2026 Call about rent"
+    );
+    assert_eq!(exported[1].neighbor_follow_ups, "");
+    Ok(())
+}
+
+#[tokio::test]
+async fn pending_assistance_is_listed_and_marked_paid() -> anyhow::Result<()> {
+    let mut state = seeded();
+    state.assistance_types = vec![LookupOption {
+        id: "16530".into(),
+        name: "Rent".into(),
+    }];
+    let server = MockServer::start(state).await?;
+    let client = login(&server).await?;
+
+    let rent = UpdateAssistanceInput {
+        payee_name: "Acme Apartments".into(),
+        check_requested: true,
+        pending: true,
+        ..UpdateAssistanceInput::new("16530", "9001", "1250", "1", "03/02/2026")
+    };
+    client.update_assistance(101, &rent).await?;
+    let food = UpdateAssistanceInput::new("16530", "9002", "70", "1", "03/02/2026");
+    client.update_assistance(102, &food).await?;
+
+    let pending = client.fetch_pending_assistance().await?;
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].request_id, 101);
+    assert_eq!(pending[0].neighbor, "Lopez, Ana");
+    assert_eq!(pending[0].assistance_type, "Rent");
+    assert_eq!(pending[0].amount, Some(1250.0));
+    assert_eq!(pending[0].payee_name, "Acme Apartments");
+
+    let dir = tempfile::tempdir()?;
+    let csv = dir.path().join("pending.csv");
+    nativity::pending_to_csv(&client, &csv).await?;
+    let mut rows: Vec<nativity::PendingPayment> = csv::Reader::from_path(&csv)?
+        .deserialize()
+        .collect::<Result<_, _>>()?;
+    assert_eq!(rows.len(), 1);
+    rows[0].check_number = "1042".into();
+    rows[0].date_paid = Some("03/09/2026".into());
+    let mut writer = csv::Writer::from_path(&csv)?;
+    for row in &rows {
+        writer.serialize(row)?;
+    }
+    writer.flush()?;
+
+    nativity::mark_paid(&client, &csv).await?;
+    {
+        let state = server.state();
+        let form = &state.item_updates[0];
+        assert_eq!(form.id, pending[0].item_id);
+        assert_eq!(form.get("pending"), None);
+        assert_eq!(form.get("_pending"), Some("on"));
+        assert_eq!(form.get("checkNumber"), Some("1042"));
+        assert_eq!(form.get("datePaid"), Some("03/09/2026"));
+        // Untouched fields are posted back as they were.
+        assert_eq!(form.get("payeeName"), Some("Acme Apartments"));
+        assert_eq!(form.get("checkRequested"), Some("true"));
+        // Nothing from the page's other forms.
+        assert_eq!(form.get("confirmDelete"), None);
+    }
+    assert!(client.fetch_pending_assistance().await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn service_hours_are_logged_and_listed_by_month() -> anyhow::Result<()> {
+    let state = seeded().with_service_hours("44270", "02/27/2026", 3.0, 0.0);
+    let server = MockServer::start(state).await?;
+    let client = login(&server).await?;
+
+    let dir = tempfile::tempdir()?;
+    let hours = dir.path().join("hours.csv");
+    std::fs::write(
+        &hours,
+        "volunteer_id,date,hours,miles,notes\n\
+         44270,03/04/2026,2.5,12,Pantry shift\n\
+         44270,03/18/2026,1,,Conference meeting\n",
+    )?;
+    nativity::log_hours(&client, &hours).await?;
+    {
+        let state = server.state();
+        let form = &state.created_service_hours[0];
+        assert_eq!(form.get("memberId"), Some("44270"));
+        assert_eq!(form.get("dateOfService"), Some("03/04/2026"));
+        assert_eq!(form.get("hoursInService"), Some("2.5"));
+        assert_eq!(form.get("mileageInService"), Some("12"));
+        assert_eq!(form.get("notes"), Some("Pantry shift"));
+        assert_eq!(
+            state.created_service_hours[1].get("mileageInService"),
+            Some("0")
+        );
+    }
+
+    let march_start = chrono::NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+    let march = client
+        .service_hours_between(
+            march_start,
+            chrono::NaiveDate::from_ymd_opt(2026, 3, 31).unwrap(),
+        )
+        .await?;
+    assert_eq!(march.len(), 2);
+    assert_eq!(march[0].member.as_deref(), Some("Jane Volunteer"));
+    let total: f64 = march.iter().map(|e| e.hours_in_service).sum();
+    assert_eq!(total, 3.5);
+
+    let entry = NewServiceHours {
+        hours_in_service: "a few".into(),
+        ..NewServiceHours::new("44270", march_start, 1.0, 0.0)
+    };
+    let err = client.create_service_hours(&entry).await.unwrap_err();
+    assert_eq!(
+        err.field_errors()[0].field.as_deref(),
+        Some("hoursInService")
+    );
+    assert_eq!(server.state().created_service_hours.len(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn activity_report_is_parsed_and_exported() -> anyhow::Result<()> {
+    let mut state = seeded();
+    state.assistance_types = vec![
+        LookupOption {
+            id: "16530".into(),
+            name: "Rent".into(),
+        },
+        LookupOption {
+            id: "16542".into(),
+            name: "Second Harvest Food".into(),
+        },
+    ];
+    let server = MockServer::start(state).await?;
+    let client = login(&server).await?;
+
+    for (request_id, type_id, value, date) in [
+        (101, "16530", "1250", "02/10/2026"),
+        (102, "16542", "70", "02/11/2026"),
+        (102, "16542", "70", "03/02/2026"),
+    ] {
+        let item = UpdateAssistanceInput::new(type_id, "", value, "1", date);
+        client.update_assistance(request_id, &item).await?;
+    }
+
+    let from = chrono::NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+    let to = chrono::NaiveDate::from_ymd_opt(2026, 2, 28).unwrap();
+    let rows = client.fetch_activity_report(from, to).await?;
+    let line = |description: &str| {
+        rows.iter()
+            .find(|r| r.description == description)
+            .unwrap_or_else(|| panic!("no {description:?} line in {rows:?}"))
+    };
+    assert_eq!(line("Completed Requests").count, Some(1));
+    assert_eq!(line("Open Requests").count, Some(2));
+    assert_eq!(line("People Helped").section, "Requests");
+    assert_eq!(line("People Helped").count, Some(6));
+    let rent = line("Rent");
+    assert_eq!(rent.section, "Assistance");
+    assert_eq!((rent.count, rent.amount), (Some(1), Some(1250.0)));
+    assert_eq!(line("Second Harvest Food").count, Some(1));
+
+    let dir = tempfile::tempdir()?;
+    let csv = dir.path().join("report.csv");
+    nativity::activity_report_to_csv(&client, from, to, &csv).await?;
+    let written = std::fs::read_to_string(&csv)?;
+    assert!(written.starts_with("section,description,count,amount\n"));
+    assert!(written.contains("Assistance,Rent,1,1250.0\n"));
+
+    let json = dir.path().join("report.json");
+    nativity::activity_report_to_json(&client, from, to, &json).await?;
+    let exported: Vec<serde_json::Value> = serde_json::from_str(&std::fs::read_to_string(&json)?)?;
+    assert_eq!(exported.len(), rows.len());
+    Ok(())
+}
+
+#[tokio::test]
+async fn expired_session_logs_in_again() -> anyhow::Result<()> {
+    let server = MockServer::start(seeded()).await?;
+    let client = login(&server).await?;
+
+    server.state().expire_sessions();
+    client.fetch_members(101).await?;
+    assert_eq!(server.state().logins, 2);
+
+    server.state().expire_sessions();
+    server.state().password = "rotated".into();
+    let err = client.fetch_members(101).await.unwrap_err();
+    assert!(err.is_session());
+    let ServWareError::ReloginFailed(cause) = err else {
+        panic!("expected a failed re-login, got {err:?}");
+    };
+    assert!(matches!(*cause, ServWareError::BadCredentials));
+    Ok(())
+}
+
+#[tokio::test]
+async fn keep_alive_extends_until_stopped() -> anyhow::Result<()> {
+    let server = MockServer::start(seeded()).await?;
+    let client = login(&server).await?;
+    assert!(matches!(
+        client.start_keep_alive(Duration::ZERO),
+        Err(ServWareError::InvalidInput(_))
+    ));
+
+    client.start_keep_alive(Duration::from_millis(20))?;
+    tokio::time::sleep(Duration::from_millis(110)).await;
+    client.stop_keep_alive();
+    let extended = server.state().session_extensions;
+    assert!(extended >= 2, "only {extended} keep-alive calls");
+
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert_eq!(server.state().session_extensions, extended);
+    Ok(())
+}
+
+#[tokio::test]
+async fn logout_ends_the_session() -> anyhow::Result<()> {
+    let server = MockServer::start(seeded()).await?;
+    let client = login(&server).await?;
+    client.ping().await?;
+
+    client.logout().await?;
+    assert_eq!(server.state().logouts, 1);
+    assert!(matches!(
+        client.ping().await,
+        Err(ServWareError::SessionExpired)
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn saved_session_is_reused_until_stale() -> anyhow::Result<()> {
+    let server = MockServer::start(seeded()).await?;
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("session.json");
+    let file = SessionFile::new(&path, Some(SecretString::from("correct horse")));
+
+    login(&server).await?.save_session(&file)?;
+    let saved = std::fs::read_to_string(&path)?;
+    assert!(!saved.contains("mock-session"));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(
+            std::fs::metadata(&path)?.permissions().mode() & 0o777,
+            0o600
+        );
+    }
+
+    let resumed = ServWare::resume_session(&server.base_url(), &file)
+        .await?
+        .expect("live session");
+    resumed.fetch_members(101).await?;
+    assert_eq!(server.state().logins, 1);
+
+    let wrong = SessionFile::new(&path, Some(SecretString::from("wrong")));
+    assert!(
+        ServWare::resume_session(&server.base_url(), &wrong)
+            .await?
+            .is_none()
+    );
+
+    let prompted = ServWare::resume_session(&server.base_url(), &file)
+        .await?
+        .expect("live session")
+        .with_password_prompt(|| Ok(SecretString::from(PASS)));
+
+    // A resumed session has no password to renew itself with unless it
+    // can ask for one.
+    server.state().expire_sessions();
+    let err = resumed.fetch_members(101).await.unwrap_err();
+    assert!(matches!(err, ServWareError::NoCredentials));
+    assert!(err.is_session());
+    prompted.fetch_members(101).await?;
+    assert_eq!(server.state().logins, 2);
+
+    assert!(
+        ServWare::resume_session(&server.base_url(), &file)
+            .await?
+            .is_none()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn encrypted_session_is_not_overwritten_without_the_passphrase() -> anyhow::Result<()> {
+    let server = MockServer::start(seeded()).await?;
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("session.json");
+    let encrypted = SessionFile::new(&path, Some(SecretString::from("correct horse")));
+    login(&server).await?.save_session(&encrypted)?;
+
+    let plain = SessionFile::new(&path, None);
+    assert!(plain.is_locked());
+    assert!(
+        ServWare::resume_session(&server.base_url(), &plain)
+            .await?
+            .is_none()
+    );
+    assert!(login(&server).await?.save_session(&plain).is_err());
+
+    let saved: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+    assert_eq!(saved["cookies"]["kind"], "encrypted");
+    assert!(!encrypted.is_locked());
+    assert!(
+        ServWare::resume_session(&server.base_url(), &encrypted)
+            .await?
+            .is_some()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn rejected_forms_are_reported_with_their_field_errors() -> anyhow::Result<()> {
+    let server = MockServer::start(seeded()).await?;
+    let client = login(&server).await?;
+
+    let deny = UpdateRequestInput {
+        status: Some("Denied".into()),
+        ..Default::default()
+    };
+    let Err(ServWareError::Validation { context, errors }) =
+        client.update_request(101, &deny).await
+    else {
+        panic!("a denial without a reason should be rejected");
+    };
+    assert_eq!(context, "update request");
+    assert_eq!(
+        errors,
+        [FieldError {
+            field: Some("denialReasonId".into()),
+            message: "A denial reason is required.".into(),
+        }]
+    );
+
+    let item = UpdateAssistanceInput::new("16530", "9002", "seventy", "1", "");
+    let err = client.update_assistance(102, &item).await.unwrap_err();
+    let fields: Vec<_> = err
+        .field_errors()
+        .iter()
+        .map(|e| e.field.as_deref())
+        .collect();
+    assert_eq!(fields, [Some("monetaryValue"), Some("dateProvided")]);
+    assert!(err.to_string().contains("Date provided is required."));
+
+    let state = server.state();
+    assert_eq!(state.request_status(101), Some("Open"));
+    assert!(state.request_updates.is_empty());
+    assert!(state.assistance_items.is_empty());
+    assert_eq!(state.rejected_forms.len(), 2);
+    Ok(())
+}